@@ -0,0 +1,270 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::{CellIndex, PlayerId};
+
+/// The different ways a property can be auctioned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuctionKind {
+    /// Players take turns raising the highest bid until everyone else drops out.
+    OpenOutcry,
+
+    /// Every player submits a single hidden bid and the highest bid wins.
+    ///
+    /// Ties go to the bidder that comes first in the turn order.
+    SealedBid,
+}
+
+/// A bid made by a player during an auction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Bid {
+    /// Bid the given amount.
+    Amount(usize),
+
+    /// Leave the auction.
+    DropOut,
+}
+
+/// The reasons a bid can be rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BidError {
+    /// The bidder can't cover the bid with their money.
+    InsufficientFunds { bid: usize, money: usize },
+
+    /// The bid is lower than the minimum allowed bid.
+    TooLow { bid: usize, minimum: usize },
+
+    /// The bidder isn't (or is no longer) taking part in the auction.
+    NotBidding,
+
+    /// The highest bidder can't drop out of an open-outcry auction.
+    HighestBidder,
+}
+
+impl Display for BidError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BidError::InsufficientFunds { bid, money } => {
+                f.write_fmt(format_args!("Bid of ${} exceeds available ${}", bid, money))
+            }
+            BidError::TooLow { bid, minimum } => f.write_fmt(format_args!(
+                "Bid of ${} is below the minimum ${}",
+                bid, minimum
+            )),
+            BidError::NotBidding => f.write_str("Bidder is not part of the auction"),
+            BidError::HighestBidder => f.write_str("The highest bidder can't drop out"),
+        }
+    }
+}
+
+/// The outcome of an auction, which players see as an
+/// [`AuctionWon`](crate::GameEvent::AuctionWon) or [`AuctionUnsold`](crate::GameEvent::AuctionUnsold)
+/// event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AuctionResult {
+    /// The property was sold to the winner for the given price.
+    ///
+    /// The winner is the player's index in the turn order.
+    Won { winner: PlayerId, price: usize },
+
+    /// Nobody bid on the property, so it stays with the bank.
+    NoBids,
+}
+
+/// An auction of a single property among a set of players.
+///
+/// Bids are placed one at a time, with [`Auction::next_bidder`] saying whose bid is expected.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Auction {
+    /// The position of the property being auctioned.
+    position: CellIndex,
+
+    /// The type of auction being run.
+    kind: AuctionKind,
+
+    /// The amount each new bid must beat the highest bid by.
+    min_increment: usize,
+
+    /// The players still taking part in the auction.
+    bidders: Vec<PlayerId>,
+
+    /// The index into `bidders` where the search for the next bidder starts.
+    turn: usize,
+
+    /// The highest bidder and their bid.
+    highest: Option<(PlayerId, usize)>,
+}
+
+impl Auction {
+    pub(crate) fn new(
+        position: CellIndex,
+        bidders: Vec<PlayerId>,
+        kind: AuctionKind,
+        min_increment: usize,
+    ) -> Self {
+        Self {
+            position,
+            kind,
+            min_increment: min_increment.max(1),
+            bidders,
+            turn: 0,
+            highest: None,
+        }
+    }
+
+    /// The position of the property being auctioned.
+    pub(crate) fn position(&self) -> CellIndex {
+        self.position
+    }
+
+    /// The lowest amount the next bid can be.
+    pub(crate) fn minimum_bid(&self) -> usize {
+        match (self.kind, self.highest) {
+            (AuctionKind::OpenOutcry, Some((_, amount))) => amount + self.min_increment,
+            _ => 1,
+        }
+    }
+
+    /// The index of the player whose bid is expected next, or `None` once the auction is over.
+    ///
+    /// Open-outcry auctions go around the bidders (skipping the highest bidder) until everyone
+    /// else has dropped out. Sealed-bid auctions ask each bidder once.
+    pub(crate) fn next_bidder(&self) -> Option<PlayerId> {
+        match self.kind {
+            AuctionKind::OpenOutcry => {
+                if self.is_settled() {
+                    return None;
+                }
+                (0..self.bidders.len())
+                    .map(|offset| self.bidders[(self.turn + offset) % self.bidders.len()])
+                    .find(|&bidder| self.highest.is_none_or(|(highest, _)| highest != bidder))
+            }
+            AuctionKind::SealedBid => self.bidders.get(self.turn).copied(),
+        }
+    }
+
+    /// Places a bid for the given bidder, validating it against the bidder's money and the
+    /// minimum bid.
+    ///
+    /// A rejected bid leaves the auction unchanged.
+    pub(crate) fn place_bid(
+        &mut self,
+        bidder: PlayerId,
+        bid: Bid,
+        money: usize,
+    ) -> Result<(), BidError> {
+        let pos = self
+            .bidders
+            .iter()
+            .position(|&other| other == bidder)
+            .ok_or(BidError::NotBidding)?;
+
+        let amount = match bid {
+            Bid::Amount(amount) => amount,
+            Bid::DropOut => {
+                match self.kind {
+                    AuctionKind::OpenOutcry => {
+                        if self.highest.is_some_and(|(highest, _)| highest == bidder) {
+                            return Err(BidError::HighestBidder);
+                        }
+                        self.bidders.remove(pos);
+                        self.turn = pos;
+                    }
+                    AuctionKind::SealedBid => self.turn = pos + 1,
+                }
+                return Ok(());
+            }
+        };
+
+        if amount > money {
+            return Err(BidError::InsufficientFunds { bid: amount, money });
+        }
+        let minimum = self.minimum_bid();
+        if amount < minimum {
+            return Err(BidError::TooLow {
+                bid: amount,
+                minimum,
+            });
+        }
+
+        // Sealed bids only replace the highest bid if they beat it; earlier bidders win ties
+        if self.highest.is_none_or(|(_, highest)| amount > highest) {
+            self.highest = Some((bidder, amount));
+        }
+        self.turn = pos + 1;
+        Ok(())
+    }
+
+    /// The outcome of the auction, based on the bids placed so far.
+    pub(crate) fn result(&self) -> AuctionResult {
+        match self.highest {
+            Some((winner, price)) => AuctionResult::Won { winner, price },
+            None => AuctionResult::NoBids,
+        }
+    }
+
+    /// Checks if the open-outcry auction is over (everyone except the highest bidder dropped out).
+    fn is_settled(&self) -> bool {
+        match (self.bidders.as_slice(), self.highest) {
+            ([], _) => true,
+            ([only], Some((highest, _))) => *only == highest,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highest_bidder_cannot_drop_out() {
+        let mut auction = Auction::new(1, vec![0, 1], AuctionKind::OpenOutcry, 1);
+        auction.place_bid(0, Bid::Amount(10), 100).unwrap();
+
+        assert_eq!(
+            auction.place_bid(0, Bid::DropOut, 100),
+            Err(BidError::HighestBidder)
+        );
+        assert_eq!(auction.next_bidder(), Some(1));
+        auction.place_bid(1, Bid::DropOut, 100).unwrap();
+        assert_eq!(auction.next_bidder(), None);
+        assert_eq!(
+            auction.result(),
+            AuctionResult::Won {
+                winner: 0,
+                price: 10
+            }
+        );
+    }
+
+    #[test]
+    fn sealed_bid_ties_go_to_the_earlier_bidder() {
+        let mut auction = Auction::new(1, vec![0, 1, 2], AuctionKind::SealedBid, 1);
+        auction.place_bid(0, Bid::Amount(50), 100).unwrap();
+        auction.place_bid(1, Bid::Amount(80), 100).unwrap();
+        auction.place_bid(2, Bid::Amount(80), 100).unwrap();
+
+        assert_eq!(auction.next_bidder(), None);
+        assert_eq!(
+            auction.result(),
+            AuctionResult::Won {
+                winner: 1,
+                price: 80
+            }
+        );
+    }
+
+    #[test]
+    fn auction_without_bids_is_unsold() {
+        for kind in [AuctionKind::OpenOutcry, AuctionKind::SealedBid] {
+            let mut auction = Auction::new(1, vec![0, 1], kind, 1);
+            auction.place_bid(0, Bid::DropOut, 100).unwrap();
+            auction.place_bid(1, Bid::DropOut, 100).unwrap();
+
+            assert_eq!(auction.next_bidder(), None);
+            assert_eq!(auction.result(), AuctionResult::NoBids);
+        }
+    }
+}
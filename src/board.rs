@@ -1,19 +1,23 @@
-use std::{cell::RefCell, fmt::Display, rc::Rc};
+use std::fmt::Display;
 
 use positions::FREE_PARKING;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    property::{Property, PropertyGroup, Rent},
-    utils::RcCell,
+    deck::{Deck, DeckMode},
+    property::{Property, PropertyGroup, Rent, HOTEL},
+    utils::{CellIndex, PlayerId},
 };
 
 pub(crate) const NUM_CELLS: usize = 40;
 pub(crate) const NUM_CHANCE: usize = 16;
 pub(crate) const NUM_COMMUNITY_CHEST: usize = 16;
+pub(crate) const NUM_HOUSES: usize = 32;
+pub(crate) const NUM_HOTELS: usize = 12;
 
 /// Represents a position on the board.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) enum BoardCell {
     /// The initial position of all player.
     ///
@@ -63,8 +67,9 @@ impl Display for BoardCell {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub(crate) enum ChanceCard {
+/// The various chance cards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChanceCard {
     /// Advance to "Go", collect $200.
     AdvanceToGo = 0,
 
@@ -118,8 +123,8 @@ pub(crate) enum ChanceCard {
 }
 
 /// The various community chest cards.
-#[derive(Debug, Clone, Copy)]
-pub(crate) enum CommunityChestCard {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommunityChestCard {
     /// Advance to "Go", collect $200.
     AdvanceToGo = 0,
 
@@ -169,25 +174,39 @@ pub(crate) enum CommunityChestCard {
     Inherit,
 }
 
-#[derive(Debug)]
+/// The deck a held "Get out of jail free" card came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum JailCard {
+    Chance,
+    CommunityChest,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct Board {
     /// All possible positions on the board.
-    pub(crate) cells: Vec<RcCell<BoardCell>>,
+    pub(crate) cells: Vec<BoardCell>,
+
+    /// The chance cards.
+    pub(crate) chance_cards: Deck<ChanceCard>,
 
-    /// All possible chance cards.
-    pub(crate) chance_cards: Vec<RcCell<ChanceCard>>,
+    /// The community chest cards.
+    pub(crate) community_chest_cards: Deck<CommunityChestCard>,
 
-    /// All possible community chest cards.
-    pub(crate) community_chest_cards: Vec<RcCell<CommunityChestCard>>,
+    /// The number of houses left in the bank.
+    pub(crate) houses: usize,
+
+    /// The number of hotels left in the bank.
+    pub(crate) hotels: usize,
 }
 
 impl Board {
-    pub(crate) fn new() -> Self {
+    /// Sets up the board, shuffling the card piles with the given random number generator.
+    pub(crate) fn new(deck_mode: DeckMode, rng: &mut impl Rng) -> Self {
         // Initialize board
         let mut cells = Vec::with_capacity(NUM_CELLS);
         {
-            cells.push(Rc::new(RefCell::new(BoardCell::Go)));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+            cells.push(BoardCell::Go);
+            cells.push(BoardCell::Property(Property {
                 name: "Mediterranean Avenue".into(),
                 group: PropertyGroup::Brown,
                 price: 60,
@@ -203,9 +222,11 @@ impl Board {
                     hotel: 250,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::CommunityChest)));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::CommunityChest);
+            cells.push(BoardCell::Property(Property {
                 name: "Baltic Avenue".into(),
                 group: PropertyGroup::Brown,
                 price: 60,
@@ -221,9 +242,11 @@ impl Board {
                     hotel: 450,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Tax(200)))); // Income tax
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Tax(200)); // Income tax
+            cells.push(BoardCell::Property(Property {
                 name: "Reading Railroad".into(),
                 group: PropertyGroup::Railroad,
                 price: 200,
@@ -236,8 +259,10 @@ impl Board {
                     owned4: 200,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Property(Property {
                 name: "Oriental Avenue".into(),
                 group: PropertyGroup::LightBlue,
                 price: 100,
@@ -253,9 +278,11 @@ impl Board {
                     hotel: 550,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Chance)));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Chance);
+            cells.push(BoardCell::Property(Property {
                 name: "Vermont Avenue".into(),
                 group: PropertyGroup::LightBlue,
                 price: 100,
@@ -271,8 +298,10 @@ impl Board {
                     hotel: 550,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Property(Property {
                 name: "Connecticut Avenue".into(),
                 group: PropertyGroup::LightBlue,
                 price: 120,
@@ -288,9 +317,11 @@ impl Board {
                     hotel: 600,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Jail)));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Jail);
+            cells.push(BoardCell::Property(Property {
                 name: "St. Charles Place".into(),
                 group: PropertyGroup::Pink,
                 price: 140,
@@ -306,8 +337,10 @@ impl Board {
                     hotel: 750,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Property(Property {
                 name: "Electric Company".into(),
                 group: PropertyGroup::Utility,
                 price: 150,
@@ -318,8 +351,10 @@ impl Board {
                     monopoly: 10,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Property(Property {
                 name: "States Avenue".into(),
                 group: PropertyGroup::Pink,
                 price: 140,
@@ -335,8 +370,10 @@ impl Board {
                     hotel: 750,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Property(Property {
                 name: "Virginia Avenue".into(),
                 group: PropertyGroup::Pink,
                 price: 160,
@@ -352,8 +389,10 @@ impl Board {
                     hotel: 900,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Property(Property {
                 name: "Pennsylvania Railroad".into(),
                 group: PropertyGroup::Railroad,
                 price: 200,
@@ -366,8 +405,10 @@ impl Board {
                     owned4: 200,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Property(Property {
                 name: "St. James Place".into(),
                 group: PropertyGroup::Orange,
                 price: 180,
@@ -383,9 +424,11 @@ impl Board {
                     hotel: 950,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::CommunityChest)));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::CommunityChest);
+            cells.push(BoardCell::Property(Property {
                 name: "Tennessee Avenue".into(),
                 group: PropertyGroup::Orange,
                 price: 180,
@@ -401,8 +444,10 @@ impl Board {
                     hotel: 950,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Property(Property {
                 name: "New York Avenue".into(),
                 group: PropertyGroup::Orange,
                 price: 200,
@@ -418,9 +463,11 @@ impl Board {
                     hotel: 1000,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::FreeParking(0))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::FreeParking(0));
+            cells.push(BoardCell::Property(Property {
                 name: "Kentucky Avenue".into(),
                 group: PropertyGroup::Red,
                 price: 220,
@@ -436,9 +483,11 @@ impl Board {
                     hotel: 1050,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Chance)));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Chance);
+            cells.push(BoardCell::Property(Property {
                 name: "Indiana Avenue".into(),
                 group: PropertyGroup::Red,
                 price: 220,
@@ -454,8 +503,10 @@ impl Board {
                     hotel: 1050,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Property(Property {
                 name: "Illinois Avenue".into(),
                 group: PropertyGroup::Red,
                 price: 240,
@@ -471,8 +522,10 @@ impl Board {
                     hotel: 1100,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Property(Property {
                 name: "B. & O. Railroad".into(),
                 group: PropertyGroup::Railroad,
                 price: 200,
@@ -485,8 +538,10 @@ impl Board {
                     owned4: 200,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Property(Property {
                 name: "Atlantic Avenue".into(),
                 group: PropertyGroup::Yellow,
                 price: 260,
@@ -502,8 +557,10 @@ impl Board {
                     hotel: 1150,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Property(Property {
                 name: "Ventnor Avenue".into(),
                 group: PropertyGroup::Yellow,
                 price: 260,
@@ -519,8 +576,10 @@ impl Board {
                     hotel: 1150,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Property(Property {
                 name: "Water Works".into(),
                 group: PropertyGroup::Utility,
                 price: 150,
@@ -531,8 +590,10 @@ impl Board {
                     monopoly: 10,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Property(Property {
                 name: "Marvin Gardens".into(),
                 group: PropertyGroup::Yellow,
                 price: 280,
@@ -548,9 +609,11 @@ impl Board {
                     hotel: 1200,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::GoToJail)));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::GoToJail);
+            cells.push(BoardCell::Property(Property {
                 name: "Pacific Avenue".into(),
                 group: PropertyGroup::Green,
                 price: 300,
@@ -566,8 +629,10 @@ impl Board {
                     hotel: 1275,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Property(Property {
                 name: "North Carolina Avenue".into(),
                 group: PropertyGroup::Green,
                 price: 300,
@@ -583,9 +648,11 @@ impl Board {
                     hotel: 1275,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::CommunityChest)));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::CommunityChest);
+            cells.push(BoardCell::Property(Property {
                 name: "Pennsylvania Avenue".into(),
                 group: PropertyGroup::Green,
                 price: 320,
@@ -601,8 +668,10 @@ impl Board {
                     hotel: 1400,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Property(Property {
                 name: "Short Line".into(),
                 group: PropertyGroup::Railroad,
                 price: 200,
@@ -615,9 +684,11 @@ impl Board {
                     owned4: 200,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Chance)));
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Chance);
+            cells.push(BoardCell::Property(Property {
                 name: "Park Place".into(),
                 group: PropertyGroup::DarkBlue,
                 price: 350,
@@ -633,9 +704,11 @@ impl Board {
                     hotel: 1500,
                 },
                 owner: None,
-            }))));
-            cells.push(Rc::new(RefCell::new(BoardCell::Tax(100)))); // Luxury tax
-            cells.push(Rc::new(RefCell::new(BoardCell::Property(Property {
+                buildings: 0,
+                mortgaged: false,
+            }));
+            cells.push(BoardCell::Tax(100)); // Luxury tax
+            cells.push(BoardCell::Property(Property {
                 name: "Boardwalk".into(),
                 group: PropertyGroup::DarkBlue,
                 price: 400,
@@ -651,119 +724,202 @@ impl Board {
                     hotel: 2000,
                 },
                 owner: None,
-            }))));
+                buildings: 0,
+                mortgaged: false,
+            }));
         }
 
         // Initialize Community Chest
         let mut community_chest_cards = Vec::with_capacity(NUM_COMMUNITY_CHEST);
         {
-            community_chest_cards.push(Rc::new(RefCell::new(CommunityChestCard::AdvanceToGo)));
-            community_chest_cards.push(Rc::new(RefCell::new(
-                CommunityChestCard::BankErrorInYourFavor,
-            )));
-            community_chest_cards.push(Rc::new(RefCell::new(CommunityChestCard::DoctorsFees)));
-            community_chest_cards.push(Rc::new(RefCell::new(CommunityChestCard::SaleOfStock)));
-            community_chest_cards.push(Rc::new(RefCell::new(CommunityChestCard::GetOutOfJailFree)));
-            community_chest_cards.push(Rc::new(RefCell::new(CommunityChestCard::GoToJail)));
-            community_chest_cards.push(Rc::new(RefCell::new(
-                CommunityChestCard::HolidayFundMatures,
-            )));
-            community_chest_cards.push(Rc::new(RefCell::new(CommunityChestCard::IncomeTaxRefund)));
-            community_chest_cards.push(Rc::new(RefCell::new(CommunityChestCard::Birthday)));
-            community_chest_cards.push(Rc::new(RefCell::new(
-                CommunityChestCard::LifeInsuranceMatures,
-            )));
-            community_chest_cards.push(Rc::new(RefCell::new(CommunityChestCard::HospitalFees)));
-            community_chest_cards.push(Rc::new(RefCell::new(CommunityChestCard::SchoolFees)));
-            community_chest_cards.push(Rc::new(RefCell::new(CommunityChestCard::ConsultancyFee)));
-            community_chest_cards.push(Rc::new(RefCell::new(CommunityChestCard::StreetRepairs)));
-            community_chest_cards.push(Rc::new(RefCell::new(CommunityChestCard::BeautyContest)));
-            community_chest_cards.push(Rc::new(RefCell::new(CommunityChestCard::Inherit)));
-
-            // Randomize order
-            let mut idxs = Vec::with_capacity(NUM_COMMUNITY_CHEST);
-            let mut shuffled = Vec::with_capacity(NUM_COMMUNITY_CHEST);
-            while idxs.len() < NUM_COMMUNITY_CHEST {
-                let idx = rand::thread_rng().gen_range(0..NUM_COMMUNITY_CHEST);
-                if !idxs.contains(&idx) {
-                    shuffled.push(community_chest_cards[idx].clone());
-                    idxs.push(idx);
-                }
-            }
-            community_chest_cards = shuffled;
+            community_chest_cards.push(CommunityChestCard::AdvanceToGo);
+            community_chest_cards.push(CommunityChestCard::BankErrorInYourFavor);
+            community_chest_cards.push(CommunityChestCard::DoctorsFees);
+            community_chest_cards.push(CommunityChestCard::SaleOfStock);
+            community_chest_cards.push(CommunityChestCard::GetOutOfJailFree);
+            community_chest_cards.push(CommunityChestCard::GoToJail);
+            community_chest_cards.push(CommunityChestCard::HolidayFundMatures);
+            community_chest_cards.push(CommunityChestCard::IncomeTaxRefund);
+            community_chest_cards.push(CommunityChestCard::Birthday);
+            community_chest_cards.push(CommunityChestCard::LifeInsuranceMatures);
+            community_chest_cards.push(CommunityChestCard::HospitalFees);
+            community_chest_cards.push(CommunityChestCard::SchoolFees);
+            community_chest_cards.push(CommunityChestCard::ConsultancyFee);
+            community_chest_cards.push(CommunityChestCard::StreetRepairs);
+            community_chest_cards.push(CommunityChestCard::BeautyContest);
+            community_chest_cards.push(CommunityChestCard::Inherit);
         }
 
         // Initialize Community Chest
         let mut chance_cards = Vec::with_capacity(NUM_CHANCE);
         {
-            chance_cards.push(Rc::new(RefCell::new(ChanceCard::AdvanceToGo)));
-            chance_cards.push(Rc::new(RefCell::new(ChanceCard::AdvanceToIllinois)));
-            chance_cards.push(Rc::new(RefCell::new(ChanceCard::AdvanceToStCharlesPlace)));
-            chance_cards.push(Rc::new(RefCell::new(ChanceCard::AdvanceToNearestUtility)));
-            chance_cards.push(Rc::new(RefCell::new(ChanceCard::AdvanceToNearestRailroad)));
-            chance_cards.push(Rc::new(RefCell::new(ChanceCard::Dividend)));
-            chance_cards.push(Rc::new(RefCell::new(ChanceCard::GetOutOfJailFree)));
-            chance_cards.push(Rc::new(RefCell::new(ChanceCard::GoBack3Spaces)));
-            chance_cards.push(Rc::new(RefCell::new(ChanceCard::GoToJail)));
-            chance_cards.push(Rc::new(RefCell::new(ChanceCard::GeneralRepairs)));
-            chance_cards.push(Rc::new(RefCell::new(ChanceCard::AdvanceToReadingRailroad)));
-            chance_cards.push(Rc::new(RefCell::new(ChanceCard::PoorTax)));
-            chance_cards.push(Rc::new(RefCell::new(ChanceCard::AdvanceToBoardwalk)));
-            chance_cards.push(Rc::new(RefCell::new(ChanceCard::ChairmanOfTheBoard)));
-            chance_cards.push(Rc::new(RefCell::new(ChanceCard::BuildingLoanMatures)));
-            chance_cards.push(Rc::new(RefCell::new(ChanceCard::HolidayFundMatures)));
-
-            // Randomize order
-            let mut idxs = Vec::with_capacity(NUM_CHANCE);
-            let mut shuffled = Vec::with_capacity(NUM_CHANCE);
-            while idxs.len() < NUM_CHANCE {
-                let idx = rand::thread_rng().gen_range(0..NUM_CHANCE);
-                if !idxs.contains(&idx) {
-                    shuffled.push(chance_cards[idx].clone());
-                    idxs.push(idx);
-                }
-            }
-            chance_cards = shuffled;
+            chance_cards.push(ChanceCard::AdvanceToGo);
+            chance_cards.push(ChanceCard::AdvanceToIllinois);
+            chance_cards.push(ChanceCard::AdvanceToStCharlesPlace);
+            chance_cards.push(ChanceCard::AdvanceToNearestUtility);
+            chance_cards.push(ChanceCard::AdvanceToNearestRailroad);
+            chance_cards.push(ChanceCard::Dividend);
+            chance_cards.push(ChanceCard::GetOutOfJailFree);
+            chance_cards.push(ChanceCard::GoBack3Spaces);
+            chance_cards.push(ChanceCard::GoToJail);
+            chance_cards.push(ChanceCard::GeneralRepairs);
+            chance_cards.push(ChanceCard::AdvanceToReadingRailroad);
+            chance_cards.push(ChanceCard::PoorTax);
+            chance_cards.push(ChanceCard::AdvanceToBoardwalk);
+            chance_cards.push(ChanceCard::ChairmanOfTheBoard);
+            chance_cards.push(ChanceCard::BuildingLoanMatures);
+            chance_cards.push(ChanceCard::HolidayFundMatures);
         }
 
         Self {
             cells,
-            chance_cards,
-            community_chest_cards,
+            chance_cards: Deck::new(chance_cards, deck_mode, rng),
+            community_chest_cards: Deck::new(community_chest_cards, deck_mode, rng),
+            houses: NUM_HOUSES,
+            hotels: NUM_HOTELS,
         }
     }
 
-    /// Draw from the top of community chest pile and place at the bottom.
-    pub(crate) fn draw_community_chest_card(&mut self) -> CommunityChestCard {
-        let drawn = self.community_chest_cards.pop().unwrap();
-        self.community_chest_cards.insert(0, drawn.clone());
-        drawn.clone().borrow().clone()
+    /// Draws the top community chest card.
+    ///
+    /// The card is played straight away and goes back to the deck, except for "Get out of jail
+    /// free" cards, which are held until they're returned with [`Board::return_jail_card`].
+    pub(crate) fn draw_community_chest_card(&mut self, rng: &mut impl Rng) -> CommunityChestCard {
+        let card = self
+            .community_chest_cards
+            .draw(rng)
+            .expect("only one card can be held");
+        if card != CommunityChestCard::GetOutOfJailFree {
+            self.community_chest_cards.discard(card);
+        }
+        card
     }
 
-    /// Draw from the top of chance pile and place at the bottom.
-    pub(crate) fn draw_chance_card(&mut self) -> ChanceCard {
-        let drawn = self.chance_cards.pop().unwrap();
-        self.chance_cards.insert(0, drawn.clone());
-        drawn.clone().borrow().clone()
+    /// Draws the top chance card.
+    ///
+    /// The card is played straight away and goes back to the deck, except for "Get out of jail
+    /// free" cards, which are held until they're returned with [`Board::return_jail_card`].
+    pub(crate) fn draw_chance_card(&mut self, rng: &mut impl Rng) -> ChanceCard {
+        let card = self
+            .chance_cards
+            .draw(rng)
+            .expect("only one card can be held");
+        if card != ChanceCard::GetOutOfJailFree {
+            self.chance_cards.discard(card);
+        }
+        card
+    }
+
+    /// Puts a used (or surrendered) "Get out of jail free" card back in the deck it came from.
+    pub(crate) fn return_jail_card(&mut self, card: JailCard) {
+        match card {
+            JailCard::Chance => self.chance_cards.discard(ChanceCard::GetOutOfJailFree),
+            JailCard::CommunityChest => self
+                .community_chest_cards
+                .discard(CommunityChestCard::GetOutOfJailFree),
+        }
     }
 
     /// Adds the given amount to free parking.
     pub(crate) fn add_to_free_parking(&mut self, amount: usize) {
-        let mut cell = self.cells[positions::FREE_PARKING].borrow_mut();
-        if let BoardCell::FreeParking(curr) = *cell {
-            *cell = BoardCell::FreeParking(curr + amount);
+        if let BoardCell::FreeParking(curr) = &mut self.cells[positions::FREE_PARKING] {
+            *curr += amount;
         }
     }
 
     /// Removes all the money from free parking.
     pub(crate) fn remove_from_free_parking(&mut self) -> usize {
-        let mut cell = self.cells[positions::FREE_PARKING].borrow_mut();
-        if let BoardCell::FreeParking(curr) = *cell {
-            *cell = BoardCell::FreeParking(0);
-            return curr;
+        if let BoardCell::FreeParking(curr) = &mut self.cells[positions::FREE_PARKING] {
+            return std::mem::take(curr);
         }
         unreachable!()
     }
+
+    /// Gets the property at the given position, if there is one.
+    pub(crate) fn property_at(&self, position: CellIndex) -> Option<&Property> {
+        match self.cells.get(position)? {
+            BoardCell::Property(property) => Some(property),
+            _ => None,
+        }
+    }
+
+    /// Gets the property at the given position for modification, if there is one.
+    pub(crate) fn property_at_mut(&mut self, position: CellIndex) -> Option<&mut Property> {
+        match self.cells.get_mut(position)? {
+            BoardCell::Property(property) => Some(property),
+            _ => None,
+        }
+    }
+
+    /// Gets the position of the next property in the given group, moving forward from the given
+    /// position.
+    pub(crate) fn nearest(&self, position: CellIndex, group: PropertyGroup) -> CellIndex {
+        (1..=NUM_CELLS)
+            .map(|offset| (position + offset) % NUM_CELLS)
+            .find(|&position| {
+                self.property_at(position)
+                    .is_some_and(|property| property.group == group)
+            })
+            .expect("group has at least one property")
+    }
+
+    /// Gets all the properties in the given group.
+    pub(crate) fn properties_in_group(&self, group: PropertyGroup) -> Vec<&Property> {
+        self.properties()
+            .filter(|(_, property)| property.group == group)
+            .map(|(_, property)| property)
+            .collect()
+    }
+
+    /// Gets every property on the board, along with its position.
+    pub(crate) fn properties(&self) -> impl Iterator<Item = (CellIndex, &Property)> {
+        (0..NUM_CELLS).filter_map(|position| Some((position, self.property_at(position)?)))
+    }
+
+    /// Gets the positions of the properties the player owns, in board order.
+    pub(crate) fn owned_by(&self, player: PlayerId) -> Vec<CellIndex> {
+        self.properties()
+            .filter(|(_, property)| property.is_owned_by(player))
+            .map(|(position, _)| position)
+            .collect()
+    }
+
+    /// Counts the properties the player owns in the given group.
+    pub(crate) fn num_owned_in_group(&self, player: PlayerId, group: PropertyGroup) -> usize {
+        self.properties_in_group(group)
+            .iter()
+            .filter(|property| property.is_owned_by(player))
+            .count()
+    }
+
+    /// The value of the player's properties and buildings.
+    ///
+    /// Mortgaged properties are worth their price minus the mortgage.
+    pub(crate) fn property_value(&self, player: PlayerId) -> usize {
+        self.properties()
+            .filter(|(_, property)| property.is_owned_by(player))
+            .map(|(_, property)| {
+                let value = if property.mortgaged {
+                    property.price - property.mortgage
+                } else {
+                    property.price
+                };
+                value + property.buildings * property.building.1
+            })
+            .sum()
+    }
+
+    /// Counts the houses and hotels on the player's properties.
+    pub(crate) fn num_buildings(&self, player: PlayerId) -> (usize, usize) {
+        self.properties()
+            .filter(|(_, property)| property.is_owned_by(player))
+            .fold((0, 0), |(houses, hotels), (_, property)| {
+                match property.buildings {
+                    HOTEL => (houses, hotels + 1),
+                    buildings => (houses + buildings, hotels),
+                }
+            })
+    }
 }
 
 /// Defines all positions as indicies.
@@ -0,0 +1,427 @@
+use crate::{Action, AuctionKind, Bid, GameView, PropertyGroup, PropertyInfo, Strategy};
+
+/// Buys every property it can afford.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlwaysBuy {
+    /// The most it will bid in an auction, as a percentage of the property's price.
+    pub bid_ceiling_percent: usize,
+}
+
+impl Default for AlwaysBuy {
+    fn default() -> Self {
+        Self {
+            bid_ceiling_percent: 100,
+        }
+    }
+}
+
+impl Strategy for AlwaysBuy {
+    fn bid(
+        &self,
+        view: &GameView,
+        property: &PropertyInfo,
+        kind: AuctionKind,
+        minimum: usize,
+    ) -> Bid {
+        let ceiling = percent_of(property.price, self.bid_ceiling_percent);
+        bid_up_to(view, kind, minimum, ceiling, 0)
+    }
+}
+
+/// Never buys or bids on anything, as a baseline to compare other strategies against.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NeverBuy;
+
+impl Strategy for NeverBuy {
+    fn wants_to_buy(&self, view: &GameView, property: &PropertyInfo) -> bool {
+        false
+    }
+
+    fn bid(
+        &self,
+        view: &GameView,
+        property: &PropertyInfo,
+        kind: AuctionKind,
+        minimum: usize,
+    ) -> Bid {
+        Bid::DropOut
+    }
+}
+
+/// Buys properties as long as it keeps a cash reserve to cover rent and fines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CashReserve {
+    /// The money it keeps on hand after buying or bidding.
+    pub reserve: usize,
+
+    /// The most it will bid in an auction, as a percentage of the property's price.
+    pub bid_ceiling_percent: usize,
+}
+
+impl Default for CashReserve {
+    fn default() -> Self {
+        Self {
+            reserve: 300,
+            bid_ceiling_percent: 100,
+        }
+    }
+}
+
+impl Strategy for CashReserve {
+    fn wants_to_buy(&self, view: &GameView, property: &PropertyInfo) -> bool {
+        can_spend(view, property.price, self.reserve)
+    }
+
+    fn bid(
+        &self,
+        view: &GameView,
+        property: &PropertyInfo,
+        kind: AuctionKind,
+        minimum: usize,
+    ) -> Bid {
+        let ceiling = percent_of(property.price, self.bid_ceiling_percent);
+        bid_up_to(view, kind, minimum, ceiling, self.reserve)
+    }
+
+    fn wants_to_unmortgage(&self, view: &GameView, property: &PropertyInfo) -> bool {
+        can_spend(view, property.unmortgage_cost, self.reserve)
+    }
+}
+
+/// Collects the color groups it prioritizes (orange and red by default), buying them whenever it
+/// can and building on them once it has a monopoly. Other properties are only bought while it
+/// keeps a cash reserve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorGroupCollector {
+    /// The groups it tries to complete.
+    pub groups: Vec<PropertyGroup>,
+
+    /// The money it keeps on hand when buying other properties or building.
+    pub reserve: usize,
+
+    /// The most it will bid for a property in one of its groups, as a percentage of the price.
+    pub priority_bid_percent: usize,
+
+    /// The most it will bid for any other property, as a percentage of the price.
+    pub bid_ceiling_percent: usize,
+}
+
+impl Default for ColorGroupCollector {
+    fn default() -> Self {
+        Self {
+            groups: vec![PropertyGroup::Orange, PropertyGroup::Red],
+            reserve: 200,
+            priority_bid_percent: 150,
+            bid_ceiling_percent: 80,
+        }
+    }
+}
+
+impl ColorGroupCollector {
+    /// Checks if the property belongs to one of the groups it collects.
+    fn is_priority(&self, property: &PropertyInfo) -> bool {
+        self.groups.contains(&property.group)
+    }
+}
+
+impl Strategy for ColorGroupCollector {
+    fn wants_to_buy(&self, view: &GameView, property: &PropertyInfo) -> bool {
+        if self.is_priority(property) {
+            can_spend(view, property.price, 0)
+        } else {
+            can_spend(view, property.price, self.reserve)
+        }
+    }
+
+    fn bid(
+        &self,
+        view: &GameView,
+        property: &PropertyInfo,
+        kind: AuctionKind,
+        minimum: usize,
+    ) -> Bid {
+        if self.is_priority(property) {
+            let ceiling = percent_of(property.price, self.priority_bid_percent);
+            bid_up_to(view, kind, minimum, ceiling, 0)
+        } else {
+            let ceiling = percent_of(property.price, self.bid_ceiling_percent);
+            bid_up_to(view, kind, minimum, ceiling, self.reserve)
+        }
+    }
+
+    fn manage_properties(&self, view: &GameView) -> Option<Action> {
+        build(view, self.reserve, |property| self.is_priority(property))
+    }
+}
+
+/// Buys whatever it can afford and builds as fast as possible, bringing every monopoly up to a
+/// target number of houses before building any further.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AggressiveBuilder {
+    /// The number of houses it builds on every property before going beyond.
+    pub target_houses: usize,
+
+    /// The money it keeps on hand when building.
+    pub reserve: usize,
+
+    /// The most it will bid in an auction, as a percentage of the property's price.
+    pub bid_ceiling_percent: usize,
+}
+
+impl Default for AggressiveBuilder {
+    fn default() -> Self {
+        Self {
+            target_houses: 3,
+            reserve: 100,
+            bid_ceiling_percent: 120,
+        }
+    }
+}
+
+impl Strategy for AggressiveBuilder {
+    fn bid(
+        &self,
+        view: &GameView,
+        property: &PropertyInfo,
+        kind: AuctionKind,
+        minimum: usize,
+    ) -> Bid {
+        let ceiling = percent_of(property.price, self.bid_ceiling_percent);
+        bid_up_to(view, kind, minimum, ceiling, 0)
+    }
+
+    fn manage_properties(&self, view: &GameView) -> Option<Action> {
+        build(view, self.reserve, |property| {
+            property.buildings < self.target_houses
+        })
+        .or_else(|| build(view, self.reserve, |_| true))
+    }
+}
+
+/// Grabs every railroad it can, paying well over the price for them at auction. Other properties
+/// are only bought while it keeps a cash reserve.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RailroadHoarder {
+    /// The money it keeps on hand when buying anything other than a railroad.
+    pub reserve: usize,
+
+    /// The most it will bid for a railroad, as a percentage of the price.
+    pub railroad_bid_percent: usize,
+
+    /// The most it will bid for any other property, as a percentage of the price.
+    pub bid_ceiling_percent: usize,
+}
+
+impl Default for RailroadHoarder {
+    fn default() -> Self {
+        Self {
+            reserve: 300,
+            railroad_bid_percent: 200,
+            bid_ceiling_percent: 80,
+        }
+    }
+}
+
+impl Strategy for RailroadHoarder {
+    fn wants_to_buy(&self, view: &GameView, property: &PropertyInfo) -> bool {
+        if property.group == PropertyGroup::Railroad {
+            can_spend(view, property.price, 0)
+        } else {
+            can_spend(view, property.price, self.reserve)
+        }
+    }
+
+    fn bid(
+        &self,
+        view: &GameView,
+        property: &PropertyInfo,
+        kind: AuctionKind,
+        minimum: usize,
+    ) -> Bid {
+        if property.group == PropertyGroup::Railroad {
+            let ceiling = percent_of(property.price, self.railroad_bid_percent);
+            bid_up_to(view, kind, minimum, ceiling, 0)
+        } else {
+            let ceiling = percent_of(property.price, self.bid_ceiling_percent);
+            bid_up_to(view, kind, minimum, ceiling, self.reserve)
+        }
+    }
+}
+
+/// The given percentage of the amount, rounded down.
+fn percent_of(amount: usize, percent: usize) -> usize {
+    amount * percent / 100
+}
+
+/// Checks if the player can spend the amount and still have the reserve left.
+fn can_spend(view: &GameView, amount: usize, reserve: usize) -> bool {
+    view.money(view.player()) >= amount + reserve
+}
+
+/// Bids as little as possible up to the ceiling, never touching the reserve.
+///
+/// Open-outcry bids raise by the minimum, while sealed bids go straight to the ceiling.
+fn bid_up_to(
+    view: &GameView,
+    kind: AuctionKind,
+    minimum: usize,
+    ceiling: usize,
+    reserve: usize,
+) -> Bid {
+    let ceiling = ceiling.min(view.money(view.player()).saturating_sub(reserve));
+    match kind {
+        AuctionKind::OpenOutcry if minimum <= ceiling => Bid::Amount(minimum),
+        AuctionKind::SealedBid if minimum <= ceiling => Bid::Amount(ceiling),
+        _ => Bid::DropOut,
+    }
+}
+
+/// Picks a building to buy on one of the properties accepted by the filter, as long as the
+/// reserve is kept.
+///
+/// The least developed properties are built on first.
+fn build(
+    view: &GameView,
+    reserve: usize,
+    filter: impl Fn(&PropertyInfo) -> bool,
+) -> Option<Action> {
+    view.legal_actions()
+        .into_iter()
+        .filter_map(|action| match action {
+            Action::BuyBuilding(position) => view.property(position),
+            _ => None,
+        })
+        .filter(|property| {
+            filter(property)
+                && property
+                    .building_cost
+                    .is_some_and(|cost| can_spend(view, cost, reserve))
+        })
+        .min_by_key(|property| property.buildings)
+        .map(|property| Action::BuyBuilding(property.position))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        board::positions::{
+            BOARDWALK, CONNECTICUT_AVENUE, ORIENTAL_AVENUE, READING_RAILROAD, ST_JAMES_PLACE,
+            VERMONT_AVENUE, VIRGINIA_AVENUE,
+        },
+        property::HOTEL,
+        testing::{brown_game, give, players, BALTIC_AVENUE, MEDITERRANEAN_AVENUE},
+        Game,
+    };
+
+    /// A game where the first player has the given amount of money.
+    fn game(money: usize) -> Game {
+        let mut game = Game::new(players());
+        game.players[0].money = money;
+        game
+    }
+
+    /// The property as the first player sees it.
+    fn property(game: &Game, position: usize) -> PropertyInfo {
+        game.view(0).property(position).unwrap()
+    }
+
+    /// Whether the strategy buys the property when it has the given amount of money.
+    fn buys(strategy: &impl Strategy, money: usize, position: usize) -> bool {
+        let game = game(money);
+        strategy.wants_to_buy(&game.view(0), &property(&game, position))
+    }
+
+    /// The strategy's sealed bid for the property when it has the given amount of money.
+    fn sealed_bid(strategy: &impl Strategy, money: usize, position: usize) -> Bid {
+        let game = game(money);
+        let property = property(&game, position);
+        strategy.bid(&game.view(0), &property, AuctionKind::SealedBid, 1)
+    }
+
+    #[test]
+    fn always_buy_buys_what_it_can_afford() {
+        let strategy = AlwaysBuy::default();
+        assert!(buys(&strategy, 400, BOARDWALK));
+        assert!(!buys(&strategy, 399, BOARDWALK));
+        assert_eq!(sealed_bid(&strategy, 1500, BOARDWALK), Bid::Amount(400));
+        assert_eq!(sealed_bid(&strategy, 250, BOARDWALK), Bid::Amount(250));
+    }
+
+    #[test]
+    fn never_buy_never_buys_or_bids() {
+        assert!(!buys(&NeverBuy, 1500, BOARDWALK));
+        assert_eq!(sealed_bid(&NeverBuy, 1500, BOARDWALK), Bid::DropOut);
+    }
+
+    #[test]
+    fn cash_reserve_keeps_its_reserve() {
+        let strategy = CashReserve::default();
+        assert!(buys(&strategy, 700, BOARDWALK));
+        assert!(!buys(&strategy, 699, BOARDWALK));
+        assert_eq!(sealed_bid(&strategy, 500, BOARDWALK), Bid::Amount(200));
+        assert_eq!(sealed_bid(&strategy, 300, BOARDWALK), Bid::DropOut);
+    }
+
+    #[test]
+    fn color_group_collector_prefers_its_groups() {
+        let strategy = ColorGroupCollector::default();
+        assert!(buys(&strategy, 180, ST_JAMES_PLACE));
+        assert!(!buys(&strategy, 180, VIRGINIA_AVENUE));
+        assert_eq!(
+            sealed_bid(&strategy, 1500, ST_JAMES_PLACE),
+            Bid::Amount(270)
+        );
+        assert_eq!(
+            sealed_bid(&strategy, 1500, VIRGINIA_AVENUE),
+            Bid::Amount(128)
+        );
+
+        // It only builds on the groups it collects
+        let game = brown_game(players(), [0, 0]);
+        assert_eq!(strategy.manage_properties(&game.view(0)), None);
+    }
+
+    #[test]
+    fn aggressive_builder_builds_evenly_up_to_its_target() {
+        let strategy = AggressiveBuilder::default();
+        let mut game = brown_game(players(), [0, 0]);
+        for position in [ORIENTAL_AVENUE, VERMONT_AVENUE, CONNECTICUT_AVENUE] {
+            give(&mut game, 0, position);
+        }
+        let positions = [
+            MEDITERRANEAN_AVENUE,
+            BALTIC_AVENUE,
+            ORIENTAL_AVENUE,
+            VERMONT_AVENUE,
+            CONNECTICUT_AVENUE,
+        ];
+
+        while let Some(action) = strategy.manage_properties(&game.view(0)) {
+            game.apply(action).unwrap();
+            let buildings = positions.map(|position| property(&game, position).buildings);
+            let least = buildings.iter().min().unwrap();
+            let most = buildings.iter().max().unwrap();
+            assert!(
+                *most <= strategy.target_houses || *least >= strategy.target_houses,
+                "every property gets {} houses first: {buildings:?}",
+                strategy.target_houses
+            );
+        }
+        for position in positions {
+            assert_eq!(property(&game, position).buildings, HOTEL);
+        }
+    }
+
+    #[test]
+    fn railroad_hoarder_pays_over_the_odds_for_railroads() {
+        let strategy = RailroadHoarder::default();
+        assert!(buys(&strategy, 200, READING_RAILROAD));
+        assert!(!buys(&strategy, 200, ORIENTAL_AVENUE));
+        assert_eq!(
+            sealed_bid(&strategy, 1500, READING_RAILROAD),
+            Bid::Amount(400)
+        );
+        assert_eq!(sealed_bid(&strategy, 1500, BOARDWALK), Bid::Amount(320));
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::VecDeque;
+
+use rand::{seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
+
+/// What happens to drawn cards once they're played.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeckMode {
+    /// Played cards go back to the bottom of the deck, so the deck keeps cycling in the order it
+    /// was first shuffled in.
+    Cycle,
+
+    /// Played cards are set aside, and are shuffled into a new deck once the deck runs out.
+    ReshuffleWhenExhausted,
+}
+
+/// A shuffled pile of cards.
+///
+/// Cards that a player holds on to (like "Get out of jail free") stay out of the deck until
+/// they're returned with [`Deck::discard`].
+///
+/// The order of the deck isn't visible to strategies; the `Debug` output lists the cards in the
+/// order they'll be drawn, for debugging.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Deck<T> {
+    /// The cards left to draw, from the top of the deck.
+    cards: VecDeque<T>,
+
+    /// The cards played since the deck was last shuffled.
+    discards: Vec<T>,
+
+    /// What happens to played cards.
+    mode: DeckMode,
+}
+
+impl<T> Deck<T> {
+    /// Creates a deck of the given cards, shuffled with the given random number generator.
+    pub(crate) fn new(mut cards: Vec<T>, mode: DeckMode, rng: &mut impl Rng) -> Self {
+        // Fisher-Yates shuffle
+        cards.shuffle(rng);
+        Self {
+            cards: cards.into(),
+            discards: vec![],
+            mode,
+        }
+    }
+
+    /// Draws the top card of the deck, shuffling the played cards into a new deck first if it ran
+    /// out.
+    ///
+    /// Returns `None` if every card is being held by the players.
+    pub(crate) fn draw(&mut self, rng: &mut impl Rng) -> Option<T> {
+        if self.cards.is_empty() {
+            self.reshuffle(rng);
+        }
+        self.cards.pop_front()
+    }
+
+    /// Puts a played (or returned) card back.
+    ///
+    /// It goes to the bottom of the deck in [`DeckMode::Cycle`], and is set aside until the next
+    /// reshuffle in [`DeckMode::ReshuffleWhenExhausted`].
+    pub(crate) fn discard(&mut self, card: T) {
+        match self.mode {
+            DeckMode::Cycle => self.cards.push_back(card),
+            DeckMode::ReshuffleWhenExhausted => self.discards.push(card),
+        }
+    }
+
+    /// Shuffles the played cards into a new deck.
+    fn reshuffle(&mut self, rng: &mut impl Rng) {
+        let mut cards = std::mem::take(&mut self.discards);
+        cards.shuffle(rng);
+        self.cards = cards.into();
+        tracing::debug!("Reshuffled the deck");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use super::*;
+    use crate::board::{Board, ChanceCard, JailCard};
+
+    fn rng() -> ChaCha8Rng {
+        ChaCha8Rng::seed_from_u64(5)
+    }
+
+    #[test]
+    fn held_jail_card_is_not_drawn_until_returned() {
+        for mode in [DeckMode::Cycle, DeckMode::ReshuffleWhenExhausted] {
+            let mut rng = rng();
+            let mut board = Board::new(mode, &mut rng);
+            while board.draw_chance_card(&mut rng) != ChanceCard::GetOutOfJailFree {}
+
+            let mut draw = |board: &mut Board| {
+                (0..32).any(|_| board.draw_chance_card(&mut rng) == ChanceCard::GetOutOfJailFree)
+            };
+            assert!(!draw(&mut board), "the card is held in {mode:?}");
+            board.return_jail_card(JailCard::Chance);
+            assert!(draw(&mut board), "the card was returned in {mode:?}");
+        }
+    }
+
+    #[test]
+    fn cycle_keeps_its_order() {
+        let mut rng = rng();
+        let mut deck = Deck::new((0..10).collect(), DeckMode::Cycle, &mut rng);
+        let mut draw_all = |deck: &mut Deck<u32>| {
+            (0..10)
+                .map(|_| {
+                    let card = deck.draw(&mut rng).unwrap();
+                    deck.discard(card);
+                    card
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let first = draw_all(&mut deck);
+        assert_ne!(first, (0..10).collect::<Vec<_>>(), "the deck is shuffled");
+        assert_eq!(draw_all(&mut deck), first);
+    }
+
+    #[test]
+    fn reshuffle_waits_for_the_deck_to_run_out() {
+        let mut rng = rng();
+        let mut deck = Deck::new(
+            (0..10).collect(),
+            DeckMode::ReshuffleWhenExhausted,
+            &mut rng,
+        );
+        let mut played: Vec<u32> = (0..5).map(|_| deck.draw(&mut rng).unwrap()).collect();
+        for &card in &played {
+            deck.discard(card);
+        }
+
+        // The rest of the deck comes first, with the played cards set aside
+        let rest: Vec<u32> = (0..5).map(|_| deck.draw(&mut rng).unwrap()).collect();
+        assert!(rest.iter().all(|card| !played.contains(card)));
+
+        // Then only the played cards are shuffled into a new deck, since the rest weren't returned
+        let mut reshuffled: Vec<u32> = (0..5).map(|_| deck.draw(&mut rng).unwrap()).collect();
+        reshuffled.sort();
+        played.sort();
+        assert_eq!(reshuffled, played);
+        assert_eq!(deck.draw(&mut rng), None);
+    }
+}
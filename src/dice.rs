@@ -0,0 +1,132 @@
+use std::fmt::Debug;
+
+use rand::{Rng, RngCore};
+
+/// A source of dice rolls.
+///
+/// Every roll in the game goes through the game's dice, so they can be swapped out to script
+/// exact rolls or to play with a different number of dice. Dice are cloned along with the game,
+/// so they must be `Clone`.
+pub trait Dice: Debug + Send + Sync + CloneDice {
+    /// Rolls the dice, returning the value of each die.
+    ///
+    /// Random dice should only use the given random number generator, so seeded games stay
+    /// reproducible.
+    fn roll(&mut self, rng: &mut dyn RngCore) -> Vec<usize>;
+}
+
+/// Checks if a roll is a double (every die showing the same value).
+///
+/// A single die never rolls doubles.
+pub(crate) fn is_doubles(dice: &[usize]) -> bool {
+    dice.len() > 1 && dice.iter().all(|&die| die == dice[0])
+}
+
+/// Clones boxed dice. Implemented for every [`Dice`] that is `Clone`.
+pub trait CloneDice {
+    /// Clones the dice into a new box.
+    fn clone_box(&self) -> Box<dyn Dice>;
+}
+
+impl<T: Dice + Clone + 'static> CloneDice for T {
+    fn clone_box(&self) -> Box<dyn Dice> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn Dice> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+/// Fair dice, two six-sided dice by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FairDice {
+    /// The number of dice rolled.
+    count: usize,
+
+    /// The number of sides on each die.
+    sides: usize,
+}
+
+impl FairDice {
+    /// Creates `count` fair dice with the given number of sides.
+    ///
+    /// # Panics
+    ///
+    /// If there are no dice or the dice have no sides.
+    pub fn new(count: usize, sides: usize) -> Self {
+        assert!(count > 0, "At least one die is needed");
+        assert!(sides > 0, "Dice need at least one side");
+        Self { count, sides }
+    }
+
+    /// The number of dice rolled.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The number of sides on each die.
+    pub fn sides(&self) -> usize {
+        self.sides
+    }
+}
+
+impl Default for FairDice {
+    fn default() -> Self {
+        Self::new(2, 6)
+    }
+}
+
+impl Dice for FairDice {
+    fn roll(&mut self, rng: &mut dyn RngCore) -> Vec<usize> {
+        (0..self.count)
+            .map(|_| rng.gen_range(1..=self.sides))
+            .collect()
+    }
+}
+
+/// Dice that return a fixed sequence of rolls, starting over once the sequence runs out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptedDice {
+    /// The rolls to return, in order.
+    rolls: Vec<Vec<usize>>,
+
+    /// The index of the next roll.
+    next: usize,
+}
+
+impl ScriptedDice {
+    /// Creates dice that roll the given pairs in order, e.g. `[(3, 3), (3, 3), (3, 3)]`.
+    ///
+    /// # Panics
+    ///
+    /// If no rolls are given.
+    pub fn new(rolls: impl IntoIterator<Item = (usize, usize)>) -> Self {
+        Self::from_rolls(rolls.into_iter().map(|(die1, die2)| vec![die1, die2]))
+    }
+
+    /// Creates dice that roll the given values in order, for any number of dice.
+    ///
+    /// # Panics
+    ///
+    /// If no rolls are given, or a roll has no dice.
+    pub fn from_rolls(rolls: impl IntoIterator<Item = Vec<usize>>) -> Self {
+        let rolls: Vec<_> = rolls.into_iter().collect();
+        assert!(!rolls.is_empty(), "At least one roll is needed");
+        assert!(
+            rolls.iter().all(|roll| !roll.is_empty()),
+            "Every roll needs at least one die"
+        );
+        Self { rolls, next: 0 }
+    }
+}
+
+impl Dice for ScriptedDice {
+    fn roll(&mut self, rng: &mut dyn RngCore) -> Vec<usize> {
+        let roll = self.rolls[self.next].clone();
+        self.next = (self.next + 1) % self.rolls.len();
+        roll
+    }
+}
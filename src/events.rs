@@ -0,0 +1,199 @@
+use std::{collections::VecDeque, fmt::Debug, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    payment::Creditor,
+    utils::{CellIndex, PlayerId},
+    ChanceCard, CommunityChestCard, EndReason, Game, Trade,
+};
+
+/// Something that happened in the game.
+///
+/// Players are referred to by their index in the turn order, and properties by their position on
+/// the board. Events are handed to every subscriber as they happen (see [`Game::subscribe`]), and
+/// can also be queued until they're drained (see [`Game::with_event_queue`]).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameEvent {
+    /// The player rolled the dice.
+    DiceRolled {
+        player: PlayerId,
+        dice: Vec<usize>,
+        doubles: bool,
+    },
+
+    /// The player moved from one position to another.
+    Moved {
+        player: PlayerId,
+        from: CellIndex,
+        to: CellIndex,
+    },
+
+    /// The player passed (or landed on) "Go" and collected their salary.
+    PassedGo { player: PlayerId, salary: usize },
+
+    /// The player drew a card.
+    CardDrawn { player: PlayerId, card: Card },
+
+    /// The player collected the free parking pot.
+    FreeParkingCollected { player: PlayerId, amount: usize },
+
+    /// The player paid rent to the owner of the property at `position`.
+    RentPaid {
+        player: PlayerId,
+        owner: PlayerId,
+        position: CellIndex,
+        amount: usize,
+    },
+
+    /// The player paid something other than rent, like a tax, a fine or a card's payment.
+    Paid {
+        player: PlayerId,
+        to: Creditor,
+        amount: usize,
+    },
+
+    /// The player bought the property at `position` at its list price.
+    PropertyBought {
+        player: PlayerId,
+        position: CellIndex,
+        price: usize,
+    },
+
+    /// The player declined to buy the property at `position`.
+    PropertyDeclined {
+        player: PlayerId,
+        position: CellIndex,
+    },
+
+    /// The player won the auction for the property at `position`.
+    AuctionWon {
+        player: PlayerId,
+        position: CellIndex,
+        price: usize,
+    },
+
+    /// Nobody bid on the property at `position`, so it stays with the bank.
+    AuctionUnsold { position: CellIndex },
+
+    /// The player bought a house (or hotel) for the property at `position`.
+    BuildingBought {
+        player: PlayerId,
+        position: CellIndex,
+        cost: usize,
+    },
+
+    /// The player sold a house (or hotel) on the property at `position`.
+    BuildingSold {
+        player: PlayerId,
+        position: CellIndex,
+        refund: usize,
+    },
+
+    /// The player mortgaged the property at `position`.
+    Mortgaged {
+        player: PlayerId,
+        position: CellIndex,
+        amount: usize,
+    },
+
+    /// The player lifted the mortgage on the property at `position`.
+    Unmortgaged {
+        player: PlayerId,
+        position: CellIndex,
+        cost: usize,
+    },
+
+    /// The player offered a trade to the player named in it.
+    TradeProposed { player: PlayerId, trade: Trade },
+
+    /// The player accepted the trade offered by `from`.
+    TradeAccepted { player: PlayerId, from: PlayerId },
+
+    /// The player turned down the trade offered by `from`.
+    TradeRejected { player: PlayerId, from: PlayerId },
+
+    /// The property at `position` was handed over to the player in a trade or by a bankrupt
+    /// player.
+    PropertyTransferred {
+        player: PlayerId,
+        position: CellIndex,
+    },
+
+    /// The player was sent to jail.
+    SentToJail { player: PlayerId },
+
+    /// The player used a "Get out of jail free" card.
+    JailCardUsed { player: PlayerId },
+
+    /// The player got out of jail.
+    LeftJail { player: PlayerId },
+
+    /// The player went bankrupt, handing everything they had over to the creditor.
+    Bankrupt {
+        player: PlayerId,
+        creditor: Creditor,
+    },
+
+    /// The player's turn ended.
+    TurnEnded { player: PlayerId },
+
+    /// The game is over.
+    GameOver { winner: PlayerId, reason: EndReason },
+}
+
+/// A chance or community chest card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Card {
+    Chance(ChanceCard),
+    CommunityChest(CommunityChestCard),
+}
+
+/// A callback handed every event as it happens.
+type Subscriber = Arc<dyn Fn(&GameEvent) + Send + Sync>;
+
+/// The callbacks subscribed to a game's events.
+#[derive(Clone, Default)]
+pub(crate) struct Subscribers(Vec<Subscriber>);
+
+impl Debug for Subscribers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{} subscribers", self.0.len()))
+    }
+}
+
+impl Game {
+    /// Calls the callback with every event from now on, as it happens.
+    ///
+    /// Subscribers are kept when the game is cloned, but not when it's saved.
+    pub fn subscribe(&mut self, callback: impl Fn(&GameEvent) + Send + Sync + 'static) {
+        self.subscribers.0.push(Arc::new(callback));
+    }
+
+    /// Queues every event from now on, to be taken with [`Game::drain_events`].
+    ///
+    /// Queued events are kept until they're drained, so long-running games should drain them
+    /// regularly. The queue isn't saved.
+    pub fn with_event_queue(mut self) -> Self {
+        self.events.get_or_insert_with(VecDeque::new);
+        self
+    }
+
+    /// Takes the events that happened since they were last drained, in order.
+    ///
+    /// Nothing is queued unless the game was set up with [`Game::with_event_queue`].
+    pub fn drain_events(&mut self) -> impl Iterator<Item = GameEvent> + '_ {
+        self.events.iter_mut().flat_map(|events| events.drain(..))
+    }
+
+    /// Hands the event to every subscriber, and queues it if events are queued.
+    pub(crate) fn emit(&mut self, event: GameEvent) {
+        for subscriber in &self.subscribers.0 {
+            subscriber(&event);
+        }
+        self.recording.record_event(&event);
+        if let Some(events) = &mut self.events {
+            events.push_back(event);
+        }
+    }
+}
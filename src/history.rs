@@ -0,0 +1,219 @@
+use std::{collections::VecDeque, fmt::Debug};
+
+use crate::{Action, Game, ReplayStep};
+
+/// The states before each recent action, so they can be undone, and the actions that were undone,
+/// so they can be redone.
+///
+/// Undo is off until a limit is set, and cloning a game doesn't copy its history.
+#[derive(Default)]
+pub(crate) struct History {
+    /// The state before each undoable action, along with the action, oldest first.
+    undo: VecDeque<(Game, Action)>,
+
+    /// The actions that were undone, most recently undone last.
+    redo: Vec<Action>,
+
+    /// The number of actions that can be undone.
+    limit: usize,
+}
+
+impl Clone for History {
+    fn clone(&self) -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: vec![],
+            limit: self.limit,
+        }
+    }
+}
+
+impl Debug for History {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "{} undoable, {} redoable",
+            self.undo.len(),
+            self.redo.len()
+        ))
+    }
+}
+
+/// A copy of a game's state, which the game can be restored to.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    game: Box<Game>,
+
+    /// The decisions recorded for a replay when the snapshot was taken, if the game is recorded.
+    steps: Option<Vec<ReplayStep>>,
+}
+
+impl Game {
+    /// Takes a copy of the game's state, to restore it with [`Game::restore`].
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            game: Box::new(self.clone()),
+            steps: self.recording.steps().map(<[ReplayStep]>::to_vec),
+        }
+    }
+
+    /// Puts the game back in the state it was in when the snapshot was taken, including the
+    /// decisions recorded for [`Game::replay`] and the undo history.
+    ///
+    /// The game keeps its subscribers and the events that weren't drained yet.
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        let mut game = (*snapshot.game).clone();
+        game.recording.set_steps(snapshot.steps.clone());
+        game.subscribers = std::mem::take(&mut self.subscribers);
+        game.events = std::mem::take(&mut self.events);
+        *self = game;
+    }
+
+    /// Takes back the last action applied with [`Game::apply`], putting the game back in the state
+    /// it was in before it.
+    ///
+    /// Returns `false` if there's nothing to undo. Events emitted by the action aren't taken back.
+    pub fn undo(&mut self) -> bool {
+        let Some((mut previous, action)) = self.history.undo.pop_back() else {
+            return false;
+        };
+        self.recording.undo_step();
+        previous.recording.set_steps(self.recording.take_steps());
+        previous.events = std::mem::take(&mut self.events);
+        previous.subscribers = std::mem::take(&mut self.subscribers);
+        previous.history = std::mem::take(&mut self.history);
+        previous.history.redo.push(action);
+        *self = previous;
+        true
+    }
+
+    /// Applies the last action taken back with [`Game::undo`] again.
+    ///
+    /// Returns `false` if there's nothing to redo. Applying any other action clears the actions
+    /// that can be redone.
+    pub fn redo(&mut self) -> bool {
+        let Some(action) = self.history.redo.pop() else {
+            return false;
+        };
+        let redo = std::mem::take(&mut self.history.redo);
+        let applied = self.apply(action.clone()).is_ok();
+        self.history.redo = redo;
+        if !applied {
+            self.history.redo.push(action);
+        }
+        applied
+    }
+
+    /// Sets the number of actions that can be undone, forgetting the oldest ones if there are
+    /// more. Undo is off (a limit of 0) by default, since every undoable action copies the game.
+    pub fn set_undo_limit(&mut self, limit: usize) {
+        self.history.limit = limit;
+        self.trim_history();
+    }
+
+    /// A copy of the game's state before an action, to undo it.
+    ///
+    /// The events aren't copied, since they're carried over when the action is undone, and
+    /// neither is the undo history, since clones start without one.
+    pub(crate) fn undo_state(&mut self) -> Option<Game> {
+        if self.history.limit == 0 {
+            return None;
+        }
+        let events = std::mem::take(&mut self.events);
+        let state = self.clone();
+        self.events = events;
+        Some(state)
+    }
+
+    /// Records the state before an action that was applied, so it can be undone.
+    pub(crate) fn push_undo_state(&mut self, state: Option<Game>, action: Action) {
+        self.history.redo.clear();
+        if let Some(state) = state {
+            self.history.undo.push_back((state, action));
+            self.trim_history();
+        }
+    }
+
+    /// Forgets the oldest undoable actions beyond the limit.
+    fn trim_history(&mut self) {
+        while self.history.undo.len() > self.history.limit {
+            self.history.undo.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{testing::players, Action, Game, GameEvent};
+
+    fn game(limit: usize) -> Game {
+        let mut game = Game::new(players()).with_seed(7);
+        game.set_undo_limit(limit);
+        game
+    }
+
+    /// Applies the first legal action.
+    fn advance(game: &mut Game) {
+        let action = game.legal_actions().remove(0);
+        game.apply(action).expect("legal actions can be applied");
+    }
+
+    #[test]
+    fn undoing_a_roll_restores_the_game() {
+        let mut game = game(1);
+        let rng = game.rng.clone();
+        game.apply(Action::RollDice).unwrap();
+        assert_ne!(game.players[0].current_position, 0);
+
+        assert!(game.undo());
+        assert_eq!(game.players[0].current_position, 0);
+        assert_eq!(game.players[0].money, 1500);
+        assert_eq!(game.rng, rng);
+        assert!(!game.undo(), "only one action was applied");
+    }
+
+    #[test]
+    fn redo_emits_the_same_events() {
+        let mut game = game(1).with_event_queue();
+        game.apply(Action::RollDice).unwrap();
+        let events: Vec<GameEvent> = game.drain_events().collect();
+
+        assert!(game.undo());
+        assert!(game.redo());
+        assert!(game.drain_events().eq(events));
+        assert!(!game.redo());
+    }
+
+    #[test]
+    fn new_actions_clear_redo() {
+        let mut game = game(1);
+        game.apply(Action::RollDice).unwrap();
+        assert!(game.undo());
+
+        game.apply(Action::RollDice).unwrap();
+        assert!(!game.redo());
+    }
+
+    #[test]
+    fn limit_trims_the_oldest_actions() {
+        let mut game = game(2);
+        for _ in 0..3 {
+            advance(&mut game);
+        }
+        assert!(!game.clone().undo(), "clones start without a history");
+
+        assert!(game.undo());
+        assert!(game.undo());
+        assert!(!game.undo());
+
+        game.set_undo_limit(0);
+        advance(&mut game);
+        assert!(!game.undo(), "undo is off");
+    }
+
+    #[test]
+    fn undo_is_off_by_default() {
+        let mut game = Game::new(players());
+        game.apply(Action::RollDice).unwrap();
+        assert!(!game.undo());
+    }
+}
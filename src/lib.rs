@@ -1,274 +1,1221 @@
 #![allow(unused)]
 
+mod auction;
 mod board;
+mod bots;
+mod deck;
+mod dice;
+mod events;
+mod history;
+mod outcome;
+mod payment;
 mod player;
 mod property;
+mod replay;
+mod rules;
+mod save;
+mod strategy;
+#[cfg(test)]
+mod testing;
+mod trade;
+mod turn;
 mod utils;
 
-use board::{Board, BoardCell};
-pub use player::Player;
-use std::{cell::RefCell, rc::Rc};
+use auction::{Auction, AuctionResult};
+pub use auction::{AuctionKind, Bid, BidError};
+use board::{Board, BoardCell, JailCard, NUM_CELLS};
+pub use board::{ChanceCard, CommunityChestCard};
+pub use bots::{
+    AggressiveBuilder, AlwaysBuy, CashReserve, ColorGroupCollector, NeverBuy, RailroadHoarder,
+};
+pub use deck::DeckMode;
+pub use dice::{CloneDice, Dice, FairDice, ScriptedDice};
+use events::Subscribers;
+pub use events::{Card, GameEvent};
+use history::History;
+pub use history::Snapshot;
+pub use outcome::{EndReason, GameOutcome, Standing};
+pub use payment::Creditor;
+pub use player::{InsufficientFunds, JailAction, Player};
+pub use property::{BuildError, MortgageError, PropertyGroup};
+use property::{Property, HOTEL};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use replay::Recording;
+pub use replay::{Divergence, Replay, ReplayStep};
+pub use rules::Rules;
+pub use save::{SaveError, SAVE_FORMAT_VERSION};
+use serde::{Deserialize, Serialize};
+use std::{collections::VecDeque, time::Instant};
+pub use strategy::{DefaultStrategy, GameView, PropertyInfo, Strategy};
 use tracing::instrument;
-use utils::RcCell;
+pub use trade::{Trade, TradeError};
+use turn::Task;
+pub use turn::{Action, ActionError, TurnPhase};
+pub use utils::{CellIndex, PlayerId};
 
 /// Represents the various possible states of the game.
-#[derive(Debug, PartialEq, PartialOrd)]
-enum GameState {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub enum GameState {
+    /// The game hasn't started yet.
     Created,
+
+    /// The game is being played.
     Running,
+
+    /// The game was paused and won't advance until it's resumed.
     Paused,
+
+    /// A winner has been determined.
     Finished,
 }
 
+/// The amount a player collects for passing "Go".
+const GO_SALARY: usize = 200;
+
+/// The amount a player must pay to get out of jail.
+const BAIL: usize = 50;
+
+/// The number of failed attempts at rolling doubles before a jailed player must pay bail.
+const MAX_JAIL_TURNS: usize = 3;
+
+/// The number of moves a player can make in a row before their new position is no longer
+/// handled.
+const MAX_CHAINED_MOVES: usize = 8;
+
+/// The number of building, selling and mortgaging actions a strategy can take in a single turn,
+/// so a strategy that never moves on can't stall the game.
+const MAX_MANAGEMENT_ACTIONS: usize = 64;
+
+/// How a player gets to a new position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Movement {
+    /// Move forward, collecting $200 if "Go" is passed.
+    Forward,
+
+    /// Move straight to the position without passing "Go".
+    Direct,
+}
+
+/// How rent is calculated when landing on an owned property.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RentRule {
+    /// The property's usual rent.
+    Normal,
+
+    /// Twice the property's usual rent.
+    Double,
+
+    /// Ten times a fresh throw of the dice, no matter how many properties the owner has.
+    TenTimesDice,
+}
+
 /// The actual game to be run by users.
-#[derive(Debug)]
+///
+/// Players and properties refer to each other by index, so the whole game can be cloned and sent
+/// between threads.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Game {
-    players: Vec<RcCell<Player>>,
+    players: Vec<Player>,
     board: Board,
     state: GameState,
+    rules: Rules,
+
+    /// The number of moves made since the current player last moved by rolling the dice.
+    chained_moves: usize,
+
+    /// The index of the player whose turn is next.
+    current_player: PlayerId,
+
+    /// The number of full rounds played.
+    rounds: usize,
+
+    /// When the game started running.
+    #[serde(with = "save::elapsed")]
+    started_at: Option<Instant>,
+
+    /// The players that went bankrupt, in the order they were eliminated.
+    eliminated: Vec<PlayerId>,
+
+    /// The result of the game, once it's finished.
+    outcome: Option<GameOutcome>,
+
+    /// The phase of the current player's turn.
+    phase: TurnPhase,
+
+    /// Work the engine still has to get through before the turn can continue.
+    tasks: VecDeque<Task>,
+
+    /// The auction being run, if any.
+    auction: Option<Auction>,
+
+    /// The trade waiting for an answer, if any.
+    trade: Option<Trade>,
+
+    /// The number of doubles the current player rolled this turn.
+    doubles_rolled: usize,
+
+    /// Whether the current player has rolled the dice this turn.
+    rolled: bool,
+
+    /// Whether the current player rolled doubles and has to roll again.
+    roll_again: bool,
+
+    /// The seed the random number generator was created from.
+    seed: u64,
+
+    /// Drives every random choice in the game (dice rolls and card shuffles).
+    rng: ChaCha8Rng,
+
+    /// The dice every roll goes through.
+    #[serde(skip, default = "save::default_dice")]
+    dice: Box<dyn Dice>,
+
+    /// The events that happened since they were last drained, if they're queued (see
+    /// [`Game::with_event_queue`]).
+    #[serde(skip)]
+    events: Option<VecDeque<GameEvent>>,
+
+    /// The callbacks handed every event as it happens.
+    #[serde(skip)]
+    subscribers: Subscribers,
+
+    /// The decisions recorded for a replay, if the game is being recorded.
+    #[serde(skip)]
+    recording: Recording,
+
+    /// The states before recent actions, to undo them.
+    #[serde(skip)]
+    history: History,
 }
 
+// Games can be moved across threads and shared between them.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Game>();
+};
+
 impl Game {
-    /// Starts a new game with the given players.
+    /// Starts a new game with the given players, using the default rules.
     pub fn new(players: Vec<Player>) -> Self {
+        Self::with_rules(players, Rules::default())
+    }
+
+    /// Starts a new game with the given players and rules.
+    ///
+    /// The game is seeded randomly; see [`Game::with_seed`] to reproduce a game.
+    pub fn with_rules(players: Vec<Player>, rules: Rules) -> Self {
         assert!(
             players.len() >= 2,
             "At least 2 players are required to start a game"
         );
         assert!(players.len() <= 6, "There can be a max of 6 players");
 
+        let seed = rand::thread_rng().gen();
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let board = Board::new(rules.deck_mode, &mut rng);
         Self {
-            players: players
-                .iter()
-                .map(|p| Rc::new(RefCell::new(p.clone())))
-                .collect(),
-            board: Board::new(),
+            players,
+            board,
             state: GameState::Created,
+            rules,
+            chained_moves: 0,
+            current_player: 0,
+            rounds: 0,
+            started_at: None,
+            eliminated: vec![],
+            outcome: None,
+            phase: TurnPhase::PreRoll,
+            tasks: VecDeque::new(),
+            auction: None,
+            trade: None,
+            doubles_rolled: 0,
+            rolled: false,
+            roll_again: false,
+            seed,
+            rng,
+            dice: Box::new(FairDice::default()),
+            events: None,
+            subscribers: Subscribers::default(),
+            recording: Recording::default(),
+            history: History::default(),
         }
     }
 
-    /// Runs the game in a loop until a winner is determined.
+    /// Reseeds the game, so every random choice (dice rolls and card shuffles) is determined by
+    /// the seed. Two games with the same players, rules and seed play out the same way.
+    ///
+    /// # Panics
+    ///
+    /// If the game has already started.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        assert!(
+            self.state == GameState::Created,
+            "Games can only be seeded before they start"
+        );
+        self.seed = seed;
+        self.rng = ChaCha8Rng::seed_from_u64(seed);
+        self.board = Board::new(self.rules.deck_mode, &mut self.rng);
+        self
+    }
+
+    /// Replaces the game's dice (two fair six-sided dice by default), e.g. with [`ScriptedDice`] to
+    /// roll an exact sequence.
+    ///
+    /// # Panics
+    ///
+    /// If the game has already started.
+    pub fn with_dice(mut self, dice: impl Dice + 'static) -> Self {
+        assert!(
+            self.state == GameState::Created,
+            "The dice can only be replaced before the game starts"
+        );
+        self.dice = Box::new(dice);
+        self
+    }
+
+    /// The seed the game's random choices are made from.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Runs the game in a loop until a winner is determined, resuming it first if it's paused.
+    ///
+    /// The game ends when only one player is left, or when the round or time limit set in the
+    /// rules is reached.
     #[instrument(skip(self))]
-    pub fn run(&mut self) {
+    pub fn run(&mut self) -> GameOutcome {
+        self.resume();
         while self.state != GameState::Finished {
             self.advance();
         }
+        self.outcome
+            .clone()
+            .expect("finished games have an outcome")
     }
 
     /// Advance the game by one round (each player gets a turn).
+    ///
+    /// If the round was already started with [`Game::step`], only the remaining players get a
+    /// turn.
     pub fn advance(&mut self) {
-        if self.state != GameState::Paused {
+        let round = self.rounds;
+        while self.rounds == round && self.can_advance() {
+            self.step();
+        }
+    }
+
+    /// Advance the game by a single turn, asking the players' strategies for every remaining
+    /// decision in it.
+    ///
+    /// Does nothing if the game is paused or finished.
+    #[instrument(skip(self))]
+    pub fn step(&mut self) {
+        let turn = (self.rounds, self.current_player);
+        let mut managed = 0;
+        while self.can_advance() && (self.rounds, self.current_player) == turn {
+            let action = self.strategy_action(managed < MAX_MANAGEMENT_ACTIONS);
+            if matches!(
+                action,
+                Action::BuyBuilding(_)
+                    | Action::SellBuilding(_)
+                    | Action::Mortgage(_)
+                    | Action::Unmortgage(_)
+                    | Action::ProposeTrade(_)
+            ) {
+                managed += 1;
+            }
+
+            if let Err(err) = self.apply(action) {
+                tracing::warn!("{}: {}", self.players[self.decision_maker()].name, err);
+                self.apply(self.fallback_action())
+                    .expect("fallback actions are always legal");
+            }
+        }
+    }
+
+    /// Pauses the game, so it won't advance until it's resumed.
+    ///
+    /// Does nothing if the game is already finished.
+    pub fn pause(&mut self) {
+        if self.state != GameState::Finished {
+            self.state = GameState::Paused;
+        }
+    }
+
+    /// Resumes a paused game.
+    pub fn resume(&mut self) {
+        if self.state == GameState::Paused {
             self.state = GameState::Running;
-            for player in self.players.clone() {
-                self.take_turn(player.clone());
-                let mut num_doubles = 0;
-                let mut last_dice = player.borrow().last_dice.unwrap();
-                while last_dice.0 == last_dice.1 {
-                    num_doubles += 1;
-
-                    // Roll again if double rolled
-                    self.take_turn(player.clone());
-                    last_dice = player.borrow().last_dice.unwrap();
-
-                    // Go to jail if 3 doubles in a row
-                    if num_doubles == 3 {
-                        let mut player = player.borrow_mut();
-                        player.in_jail = true;
-                        player.current_position = board::positions::JAIL;
-                    }
-                }
+        }
+    }
+
+    /// The current state of the game.
+    pub fn state(&self) -> GameState {
+        self.state
+    }
+
+    /// The index of the player whose turn is next.
+    pub fn current_player(&self) -> PlayerId {
+        self.current_player
+    }
+
+    /// Checks if the game can be advanced (it isn't paused or finished).
+    fn can_advance(&self) -> bool {
+        !matches!(self.state, GameState::Paused | GameState::Finished)
+    }
+
+    /// Passes the turn to the next player that hasn't gone bankrupt, ending the round once
+    /// everyone has had a turn.
+    fn next_player(&mut self) {
+        loop {
+            self.current_player = (self.current_player + 1) % self.players.len();
+            if self.current_player == 0 {
+                self.rounds += 1;
+                self.check_for_end(true);
+            }
+            if self.state == GameState::Finished || !self.players[self.current_player].bankrupt {
+                return;
             }
         }
     }
 
-    /// Simulates a player's turn by rolling dice to move them to the next position, and handling
-    /// the newly landed position.
-    #[instrument(skip(self, player))]
-    fn take_turn(&mut self, player: RcCell<Player>) {
-        // Roll dice and move the player
-        {
-            player.borrow_mut().roll_and_move();
+    /// The result of the game, if it's finished.
+    pub fn outcome(&self) -> Option<&GameOutcome> {
+        self.outcome.as_ref()
+    }
+
+    /// Buys a house for the property at the given position, or a hotel if it already has four
+    /// houses.
+    ///
+    /// The player must own every property in the group, and buildings must be spread evenly
+    /// across it. Buying a hotel returns the property's four houses to the bank.
+    ///
+    /// # Panics
+    ///
+    /// If `player` isn't a valid index into the turn order.
+    #[instrument(skip(self))]
+    pub fn buy_building(
+        &mut self,
+        player: PlayerId,
+        position: CellIndex,
+    ) -> Result<(), BuildError> {
+        let property = self.check_buy_building(player, position)?;
+        let (_, cost) = property.building;
+
+        if property.buildings == HOTEL - 1 {
+            self.board.hotels -= 1;
+            self.board.houses += HOTEL - 1;
+        } else {
+            self.board.houses -= 1;
         }
+        let property = self
+            .board
+            .property_at_mut(position)
+            .expect("checked to be a property");
+        property.buildings += 1;
+        self.players[player].money -= cost;
+        tracing::info!(
+            "{} built on {} for ${}",
+            self.players[player].name,
+            property.name,
+            cost
+        );
+        self.emit(GameEvent::BuildingBought {
+            player,
+            position,
+            cost,
+        });
+        Ok(())
+    }
 
-        let curr_space = {
-            let curr_pos = player.borrow().current_position;
-            self.board.cells[curr_pos].clone()
-        };
+    /// Sells a house (or hotel) on the property at the given position back to the bank for half
+    /// its cost, returning the amount received.
+    ///
+    /// Buildings must be sold evenly across the group. Selling a hotel takes four houses from the
+    /// bank to put back on the property.
+    ///
+    /// # Panics
+    ///
+    /// If `player` isn't a valid index into the turn order.
+    #[instrument(skip(self))]
+    pub fn sell_building(
+        &mut self,
+        player: PlayerId,
+        position: CellIndex,
+    ) -> Result<usize, BuildError> {
+        let property = self.check_sell_building(player, position)?;
+        let (_, cost) = property.building;
+
+        if property.buildings == HOTEL {
+            self.board.houses -= HOTEL - 1;
+            self.board.hotels += 1;
+        } else {
+            self.board.houses += 1;
+        }
+        let refund = cost / 2;
+        let property = self
+            .board
+            .property_at_mut(position)
+            .expect("checked to be a property");
+        property.buildings -= 1;
+        self.players[player].receive(refund);
+        tracing::info!(
+            "{} sold a building on {} for ${}",
+            self.players[player].name,
+            property.name,
+            refund
+        );
+        self.emit(GameEvent::BuildingSold {
+            player,
+            position,
+            refund,
+        });
+        Ok(refund)
+    }
+
+    /// Checks that the player can buy a building for the property at the given position.
+    fn check_buy_building(
+        &self,
+        player: PlayerId,
+        position: CellIndex,
+    ) -> Result<&Property, BuildError> {
+        let property = self.buildable_property(player, position)?;
+        let (_, cost) = property.building;
+        let group = self.board.properties_in_group(property.group);
+
+        let buildings = property.buildings;
+        if buildings >= HOTEL {
+            return Err(BuildError::FullyBuilt);
+        }
+        if group.iter().any(|p| p.buildings < buildings) {
+            return Err(BuildError::UnevenBuild);
+        }
+        if self.players[player].money < cost {
+            return Err(BuildError::InsufficientFunds);
+        }
+        if buildings == HOTEL - 1 && self.board.hotels == 0 {
+            return Err(BuildError::NoHotelsLeft);
+        }
+        if buildings < HOTEL - 1 && self.board.houses == 0 {
+            return Err(BuildError::NoHousesLeft);
+        }
+        Ok(property)
+    }
+
+    /// Checks that the player can sell a building on the property at the given position.
+    fn check_sell_building(
+        &self,
+        player: PlayerId,
+        position: CellIndex,
+    ) -> Result<&Property, BuildError> {
+        let property = self.buildable_property(player, position)?;
+        let group = self.board.properties_in_group(property.group);
+
+        let buildings = property.buildings;
+        if buildings == 0 {
+            return Err(BuildError::NoBuildings);
+        }
+        if group.iter().any(|p| p.buildings > buildings) {
+            return Err(BuildError::UnevenBuild);
+        }
+        if buildings == HOTEL && self.board.houses < HOTEL - 1 {
+            return Err(BuildError::NoHousesLeft);
+        }
+        Ok(property)
+    }
+
+    /// Gets the property at the given position, checking that the player can build on it.
+    fn buildable_property(
+        &self,
+        player: PlayerId,
+        position: CellIndex,
+    ) -> Result<&Property, BuildError> {
+        let property = self
+            .board
+            .property_at(position)
+            .ok_or(BuildError::NotAProperty)?;
+        if !property.is_owned_by(player) {
+            return Err(BuildError::NotOwner);
+        }
+        if !property.building.0 {
+            return Err(BuildError::NotBuildable);
+        }
+        if self.board.num_owned_in_group(player, property.group) != property.group.size() {
+            return Err(BuildError::NoMonopoly);
+        }
+        let group = self.board.properties_in_group(property.group);
+        if group.iter().any(|p| p.mortgaged) {
+            return Err(BuildError::Mortgaged);
+        }
+        Ok(property)
+    }
+
+    /// Mortgages the property at the given position, returning the amount received from the bank.
+    ///
+    /// All buildings in the property's group must be sold first.
+    ///
+    /// # Panics
+    ///
+    /// If `player` isn't a valid index into the turn order.
+    #[instrument(skip(self))]
+    pub fn mortgage(
+        &mut self,
+        player: PlayerId,
+        position: CellIndex,
+    ) -> Result<usize, MortgageError> {
+        self.check_mortgage(player, position)?;
+
+        let property = self
+            .board
+            .property_at_mut(position)
+            .expect("checked to be a property");
+        let amount = property.mortgage;
+        property.mortgaged = true;
+        self.players[player].receive(amount);
+        tracing::info!(
+            "{} mortgaged {} for ${}",
+            self.players[player].name,
+            property.name,
+            amount
+        );
+        self.emit(GameEvent::Mortgaged {
+            player,
+            position,
+            amount,
+        });
+        Ok(amount)
+    }
+
+    /// Lifts the mortgage on the property at the given position, returning the amount paid (the
+    /// principal plus 10% interest).
+    ///
+    /// # Panics
+    ///
+    /// If `player` isn't a valid index into the turn order.
+    #[instrument(skip(self))]
+    pub fn unmortgage(
+        &mut self,
+        player: PlayerId,
+        position: CellIndex,
+    ) -> Result<usize, MortgageError> {
+        self.check_unmortgage(player, position)?;
+
+        let property = self
+            .board
+            .property_at_mut(position)
+            .expect("checked to be a property");
+        let cost = property.unmortgage_cost();
+        property.mortgaged = false;
+        self.players[player].money -= cost;
+        tracing::info!(
+            "{} unmortgaged {} for ${}",
+            self.players[player].name,
+            property.name,
+            cost
+        );
+        self.emit(GameEvent::Unmortgaged {
+            player,
+            position,
+            cost,
+        });
+        Ok(cost)
+    }
+
+    /// Checks that the player can mortgage the property at the given position.
+    fn check_mortgage(
+        &self,
+        player: PlayerId,
+        position: CellIndex,
+    ) -> Result<&Property, MortgageError> {
+        let property = self.owned_property(player, position)?;
+        if property.mortgaged {
+            return Err(MortgageError::AlreadyMortgaged);
+        }
+        let group = self.board.properties_in_group(property.group);
+        if group.iter().any(|p| p.buildings > 0) {
+            return Err(MortgageError::HasBuildings);
+        }
+        Ok(property)
+    }
+
+    /// Checks that the player can lift the mortgage on the property at the given position.
+    fn check_unmortgage(
+        &self,
+        player: PlayerId,
+        position: CellIndex,
+    ) -> Result<&Property, MortgageError> {
+        let property = self.owned_property(player, position)?;
+        if !property.mortgaged {
+            return Err(MortgageError::NotMortgaged);
+        }
+        if self.players[player].money < property.unmortgage_cost() {
+            return Err(MortgageError::InsufficientFunds);
+        }
+        Ok(property)
+    }
+
+    /// Gets the property at the given position, checking that the player owns it.
+    fn owned_property(
+        &self,
+        player: PlayerId,
+        position: CellIndex,
+    ) -> Result<&Property, MortgageError> {
+        let property = self
+            .board
+            .property_at(position)
+            .ok_or(MortgageError::NotAProperty)?;
+        if !property.is_owned_by(player) {
+            return Err(MortgageError::NotOwner);
+        }
+        Ok(property)
+    }
+
+    /// The player's money plus the value of their properties and buildings.
+    ///
+    /// Mortgaged properties are worth their price minus the mortgage.
+    pub(crate) fn net_worth(&self, player: PlayerId) -> usize {
+        self.players[player].money + self.board.property_value(player)
+    }
+
+    /// Hands an owned property over to another player.
+    ///
+    /// If the property is mortgaged, the new owner must then decide whether to lift the mortgage
+    /// right away or pay the bank the 10% interest and keep it mortgaged (see
+    /// [`TurnPhase::MortgagedTransfer`]).
+    #[instrument(skip(self))]
+    fn transfer_property(&mut self, to: PlayerId, position: CellIndex) {
+        let property = self
+            .board
+            .property_at_mut(position)
+            .expect("only properties are transferred");
+        property.owner = Some(to);
+        let mortgaged = property.mortgaged;
+        tracing::info!("{} now owns {}", self.players[to].name, property.name);
+        self.emit(GameEvent::PropertyTransferred {
+            player: to,
+            position,
+        });
+
+        if mortgaged {
+            self.tasks.push_back(Task::MortgagedTransfer {
+                player: to,
+                position,
+            });
+        }
+    }
+
+    /// Makes the player pay bail to leave jail.
+    ///
+    /// The player is released once the bail is paid.
+    fn pay_bail(&mut self, player: PlayerId) {
+        tracing::info!("{} is paying ${} bail", self.players[player].name, BAIL);
+        let creditor = self.fine_creditor();
+        self.tasks.push_back(Task::Payment {
+            debtor: player,
+            creditor,
+            amount: BAIL,
+            rent: None,
+        });
+        self.tasks.push_back(Task::Release { player });
+    }
+
+    /// Sends the player directly to jail, without passing "Go".
+    fn send_to_jail(&mut self, player: PlayerId) {
+        let p = &mut self.players[player];
+        p.in_jail = true;
+        p.jail_turns = 0;
+        tracing::info!("{} was sent to jail", p.name);
+        self.emit(GameEvent::SentToJail { player });
+        self.move_player_to(
+            player,
+            board::positions::JAIL,
+            Movement::Direct,
+            RentRule::Normal,
+        );
+    }
+
+    /// Lets the player out of jail.
+    fn release_from_jail(&mut self, player: PlayerId) {
+        let p = &mut self.players[player];
+        p.in_jail = false;
+        p.jail_turns = 0;
+        self.emit(GameEvent::LeftJail { player });
+    }
+
+    /// Moves the player to the given position and handles the cell they land on.
+    ///
+    /// Cells reached through a long chain of moves (e.g. a card sending the player to a card that
+    /// moves them again) are no longer handled once [`MAX_CHAINED_MOVES`] is exceeded, so a
+    /// badly-behaved deck can't loop forever.
+    #[instrument(skip(self))]
+    fn move_player_to(
+        &mut self,
+        player: PlayerId,
+        position: CellIndex,
+        movement: Movement,
+        rent: RentRule,
+    ) {
+        match movement {
+            Movement::Forward => self.move_forward_to(player, position),
+            Movement::Direct => {
+                let from = std::mem::replace(&mut self.players[player].current_position, position);
+                self.emit(GameEvent::Moved {
+                    player,
+                    from,
+                    to: position,
+                });
+            }
+        }
+
+        self.chained_moves += 1;
+        if self.chained_moves > MAX_CHAINED_MOVES {
+            tracing::warn!(
+                "{} made too many moves in a row; not handling their new position",
+                self.players[player].name
+            );
+            return;
+        }
+        self.resolve_position(player, rent);
+    }
+
+    /// Handles the position the player is currently on.
+    ///
+    /// `rent` determines how rent is calculated if the position is an owned property.
+    #[instrument(skip(self))]
+    fn resolve_position(&mut self, player: PlayerId, rent: RentRule) {
+        let position = self.players[player].current_position;
+        let curr_space = self.board.cells[position].clone();
         tracing::info!(
             "Player {} landed on {}",
-            player.borrow().name,
-            curr_space.borrow()
+            self.players[player].name,
+            curr_space
         );
 
-        match &*curr_space.borrow_mut() {
+        match curr_space {
             board::BoardCell::Go => {
-                player.borrow_mut().money += 200;
+                // The salary was already collected when passing "Go"
             }
             board::BoardCell::CommunityChest => {
-                let card = self.board.draw_community_chest_card();
-                match card {
-                    board::CommunityChestCard::AdvanceToGo => {
-                        let mut player = player.borrow_mut();
-                        player.money += 200;
-                        player.current_position = board::positions::GO;
-                    }
-                    board::CommunityChestCard::BankErrorInYourFavor => {
-                        player.borrow_mut().money += 200;
-                    }
-                    board::CommunityChestCard::DoctorsFees => {
-                        // TODO: Handle if not enought money
-                        player.borrow_mut().money -= 50;
-                        self.board.add_to_free_parking(50);
-                    }
-                    board::CommunityChestCard::SaleOfStock => {
-                        player.borrow_mut().money += 50;
-                    }
-                    board::CommunityChestCard::GetOutOfJailFree => {
-                        player.borrow_mut().get_out_of_jail_free = true;
-                    }
-                    board::CommunityChestCard::GoToJail => {
-                        player.borrow_mut().current_position = board::positions::JAIL;
-                    }
-                    board::CommunityChestCard::HolidayFundMatures => {
-                        player.borrow_mut().money += 100;
-                    }
-                    board::CommunityChestCard::IncomeTaxRefund => {
-                        player.borrow_mut().money += 20;
-                    }
-                    board::CommunityChestCard::Birthday => {
-                        for other in &mut self.players {
-                            if player.borrow().name != other.borrow().name {
-                                // TODO: Pay the current player
-                                //      - Add payment methods in Player struct
-                            }
-                        }
-                    }
-                    board::CommunityChestCard::LifeInsuranceMatures => {
-                        player.borrow_mut().money += 100;
-                    }
-                    board::CommunityChestCard::HospitalFees => {
-                        // TODO: Handle if not enought money
-                        player.borrow_mut().money -= 50;
-                        self.board.add_to_free_parking(50);
-                    }
-                    board::CommunityChestCard::SchoolFees => {
-                        // TODO: Handle if not enought money
-                        player.borrow_mut().money -= 50;
-                        self.board.add_to_free_parking(50);
-                    }
-                    board::CommunityChestCard::ConsultancyFee => {
-                        player.borrow_mut().money += 25;
-                    }
-                    board::CommunityChestCard::StreetRepairs => {
-                        // TODO Implement
-                    }
-                    board::CommunityChestCard::BeautyContest => {
-                        player.borrow_mut().money += 10;
-                    }
-                    board::CommunityChestCard::Inherit => {
-                        player.borrow_mut().money += 100;
-                    }
-                }
+                let card = self.board.draw_community_chest_card(&mut self.rng);
+                self.emit(GameEvent::CardDrawn {
+                    player,
+                    card: Card::CommunityChest(card),
+                });
+                self.resolve_community_chest_card(player, card);
             }
             board::BoardCell::Tax(tax) => {
-                // TODO: Handle if not enough money!
-                player.borrow_mut().money -= tax;
+                self.pay_fine(player, tax);
             }
             board::BoardCell::Chance => {
-                let card = self.board.draw_community_chest_card();
-                match card {
-                    board::CommunityChestCard::AdvanceToGo => {
-                        let mut player = player.borrow_mut();
-                        player.money += 200;
-                        player.current_position = board::positions::GO;
-                    }
-                    board::CommunityChestCard::BankErrorInYourFavor => {
-                        player.borrow_mut().money += 200;
-                    }
-                    board::CommunityChestCard::DoctorsFees => {
-                        // TODO: Handle if not enought money
-                        player.borrow_mut().money -= 50;
-                        self.board.add_to_free_parking(50);
-                    }
-                    board::CommunityChestCard::SaleOfStock => {
-                        player.borrow_mut().money += 50;
-                    }
-                    board::CommunityChestCard::GetOutOfJailFree => {
-                        player.borrow_mut().get_out_of_jail_free = true;
-                    }
-                    board::CommunityChestCard::GoToJail => {
-                        player.borrow_mut().current_position = board::positions::JAIL;
-                    }
-                    board::CommunityChestCard::HolidayFundMatures => {
-                        player.borrow_mut().money += 100;
-                    }
-                    board::CommunityChestCard::IncomeTaxRefund => {
-                        player.borrow_mut().money += 20;
-                    }
-                    board::CommunityChestCard::Birthday => {
-                        for other in &mut self.players {
-                            if player.borrow().name != other.borrow().name {
-                                // TODO: Pay the current player
-                                //      - Add payment methods in Player struct
-                            }
-                        }
-                    }
-                    board::CommunityChestCard::LifeInsuranceMatures => {
-                        player.borrow_mut().money += 20;
-                    }
-                    board::CommunityChestCard::HospitalFees => {
-                        // TODO: Handle if not enought money
-                        player.borrow_mut().money -= 50;
-                        self.board.add_to_free_parking(50);
-                    }
-                    board::CommunityChestCard::SchoolFees => {
-                        // TODO: Handle if not enought money
-                        player.borrow_mut().money -= 50;
-                        self.board.add_to_free_parking(50);
-                    }
-                    board::CommunityChestCard::ConsultancyFee => {
-                        player.borrow_mut().money += 25;
-                    }
-                    board::CommunityChestCard::StreetRepairs => {
-                        // TODO: Implement!
-                    }
-                    board::CommunityChestCard::BeautyContest => {
-                        player.borrow_mut().money += 10;
-                    }
-                    board::CommunityChestCard::Inherit => {
-                        player.borrow_mut().money += 100;
-                    }
-                }
+                let card = self.board.draw_chance_card(&mut self.rng);
+                self.emit(GameEvent::CardDrawn {
+                    player,
+                    card: Card::Chance(card),
+                });
+                self.resolve_chance_card(player, card);
             }
             board::BoardCell::Jail => {
-                if player.borrow().in_jail == true {
-                    // TODO: Handle jail actions
-                }
-
                 // Do nothing if player is just visiting!
-                tracing::info!("{} is visiting jail.", player.borrow().name);
+                if !self.players[player].in_jail {
+                    tracing::info!("{} is visiting jail.", self.players[player].name);
+                }
             }
-            board::BoardCell::FreeParking(money) => {
-                let free_parking_money = self.board.cells[board::positions::FREE_PARKING].clone();
-                let free_parking_cell = free_parking_money.borrow();
-                if let BoardCell::FreeParking(money) = *free_parking_cell {
-                    player.borrow_mut().money += money;
+            board::BoardCell::FreeParking(_) => {
+                let amount = self.board.remove_from_free_parking();
+                self.players[player].receive(amount);
+                if amount > 0 {
+                    self.emit(GameEvent::FreeParkingCollected { player, amount });
                 }
-                *self.board.cells[board::positions::FREE_PARKING].borrow_mut() =
-                    BoardCell::FreeParking(0);
             }
             board::BoardCell::GoToJail => {
-                let mut player = player.borrow_mut();
-                player.in_jail = true;
-                player.current_position = board::positions::JAIL;
+                self.send_to_jail(player);
+            }
+            board::BoardCell::Property(property) => match property.owner {
+                Some(owner) => self.pay_rent(player, owner, position, rent),
+                None => self.offer_property(player, position),
+            },
+        };
+    }
+
+    /// Applies the effects of a drawn community chest card to the player.
+    #[instrument(skip(self))]
+    fn resolve_community_chest_card(&mut self, player: PlayerId, card: CommunityChestCard) {
+        match card {
+            CommunityChestCard::AdvanceToGo => {
+                self.advance_to(player, board::positions::GO);
+            }
+            CommunityChestCard::BankErrorInYourFavor => {
+                self.players[player].receive(200);
+            }
+            CommunityChestCard::DoctorsFees => {
+                self.pay_fine(player, 50);
+            }
+            CommunityChestCard::SaleOfStock => {
+                self.players[player].receive(50);
             }
-            board::BoardCell::Property(property) => {
-                // TODO:
-                //      - Buy/auction if unowned
-                //      - Pay rent if owned
-                //      - Pay tax if tax
-                //      - Handle if not enought money
+            CommunityChestCard::GetOutOfJailFree => {
+                self.players[player]
+                    .jail_cards
+                    .push(JailCard::CommunityChest);
             }
+            CommunityChestCard::GoToJail => {
+                self.send_to_jail(player);
+            }
+            CommunityChestCard::HolidayFundMatures => {
+                self.players[player].receive(100);
+            }
+            CommunityChestCard::IncomeTaxRefund => {
+                self.players[player].receive(20);
+            }
+            CommunityChestCard::Birthday => {
+                self.collect_from_all(player, 10);
+            }
+            CommunityChestCard::LifeInsuranceMatures => {
+                self.players[player].receive(100);
+            }
+            CommunityChestCard::HospitalFees => {
+                self.pay_fine(player, 50);
+            }
+            CommunityChestCard::SchoolFees => {
+                self.pay_fine(player, 50);
+            }
+            CommunityChestCard::ConsultancyFee => {
+                self.players[player].receive(25);
+            }
+            CommunityChestCard::StreetRepairs => {
+                self.charge_repairs(player, 40, 115);
+            }
+            CommunityChestCard::BeautyContest => {
+                self.players[player].receive(10);
+            }
+            CommunityChestCard::Inherit => {
+                self.players[player].receive(100);
+            }
+        }
+    }
+
+    /// Applies the effects of a drawn chance card to the player.
+    #[instrument(skip(self))]
+    fn resolve_chance_card(&mut self, player: PlayerId, card: ChanceCard) {
+        match card {
+            ChanceCard::AdvanceToGo => {
+                self.advance_to(player, board::positions::GO);
+            }
+            ChanceCard::AdvanceToIllinois => {
+                self.advance_to(player, board::positions::ILLINOIS_AVENUE);
+            }
+            ChanceCard::AdvanceToStCharlesPlace => {
+                self.advance_to(player, board::positions::ST_CHARLES_PLACE);
+            }
+            ChanceCard::AdvanceToNearestUtility => {
+                let position = self.players[player].current_position;
+                let utility = self.board.nearest(position, PropertyGroup::Utility);
+                self.move_player_to(player, utility, Movement::Forward, RentRule::TenTimesDice);
+            }
+            ChanceCard::AdvanceToNearestRailroad => {
+                let position = self.players[player].current_position;
+                let railroad = self.board.nearest(position, PropertyGroup::Railroad);
+                self.move_player_to(player, railroad, Movement::Forward, RentRule::Double);
+            }
+            ChanceCard::Dividend => {
+                self.players[player].receive(50);
+            }
+            ChanceCard::GetOutOfJailFree => {
+                self.players[player].jail_cards.push(JailCard::Chance);
+            }
+            ChanceCard::GoBack3Spaces => {
+                // Moving backwards never passes "Go"
+                let position = (self.players[player].current_position + NUM_CELLS - 3) % NUM_CELLS;
+                self.move_player_to(player, position, Movement::Direct, RentRule::Normal);
+            }
+            ChanceCard::GoToJail => {
+                self.send_to_jail(player);
+            }
+            ChanceCard::GeneralRepairs => {
+                self.charge_repairs(player, 25, 100);
+            }
+            ChanceCard::AdvanceToReadingRailroad => {
+                self.advance_to(player, board::positions::READING_RAILROAD);
+            }
+            ChanceCard::PoorTax => {
+                self.pay_fine(player, 15);
+            }
+            ChanceCard::AdvanceToBoardwalk => {
+                self.advance_to(player, board::positions::BOARDWALK);
+            }
+            ChanceCard::ChairmanOfTheBoard => {
+                self.pay_each(player, 50);
+            }
+            ChanceCard::BuildingLoanMatures => {
+                self.players[player].receive(150);
+            }
+            ChanceCard::HolidayFundMatures => {
+                self.players[player].receive(100);
+            }
+        }
+    }
+
+    /// Moves the player forward to the given position and handles it, collecting $200 if they
+    /// pass "Go".
+    fn advance_to(&mut self, player: PlayerId, position: CellIndex) {
+        self.move_player_to(player, position, Movement::Forward, RentRule::Normal);
+    }
+
+    /// Moves the player forward to the given position, collecting $200 if they pass "Go".
+    fn move_forward_to(&mut self, player: PlayerId, position: CellIndex) {
+        let from = self.players[player].current_position;
+        let passed_go = self.players[player].move_forward_to(position);
+        self.moved_forward(player, from, passed_go);
+    }
+
+    /// Moves the player forward by their last dice roll, collecting $200 if they pass "Go".
+    fn move_by_dice(&mut self, player: PlayerId) {
+        let from = self.players[player].current_position;
+        let passed_go = self.players[player].move_by_dice();
+        self.moved_forward(player, from, passed_go);
+    }
+
+    /// Records the player's move forward, paying their salary if they passed "Go".
+    fn moved_forward(&mut self, player: PlayerId, from: CellIndex, passed_go: bool) {
+        let to = self.players[player].current_position;
+        self.emit(GameEvent::Moved { player, from, to });
+        if passed_go {
+            self.collect_salary(player);
+        }
+    }
+
+    /// Pays the player their salary for passing "Go".
+    fn collect_salary(&mut self, player: PlayerId) {
+        let p = &mut self.players[player];
+        p.receive(GO_SALARY);
+        tracing::info!("{} passed Go and collected ${}", p.name, GO_SALARY);
+        self.emit(GameEvent::PassedGo {
+            player,
+            salary: GO_SALARY,
+        });
+    }
+
+    /// Makes the player pay for repairs on each of the houses and hotels on their properties.
+    #[instrument(skip(self))]
+    fn charge_repairs(&mut self, player: PlayerId, per_house: usize, per_hotel: usize) {
+        let (houses, hotels) = self.board.num_buildings(player);
+        let cost = houses * per_house + hotels * per_hotel;
+        if cost == 0 {
+            return;
+        }
+
+        tracing::info!(
+            "{} owes ${} in repairs for {} houses and {} hotels",
+            self.players[player].name,
+            cost,
+            houses,
+            hotels
+        );
+        self.pay_fine(player, cost);
+    }
+
+    /// Makes the player pay the owner of the property at the given position the rent due for
+    /// landing on it.
+    fn pay_rent(&mut self, player: PlayerId, owner: PlayerId, position: CellIndex, rule: RentRule) {
+        let property = self
+            .board
+            .property_at(position)
+            .expect("rent is only paid on properties");
+        let rent = match rule {
+            RentRule::Normal => self.rent_for(player, owner, property),
+            RentRule::Double => 2 * self.rent_for(player, owner, property),
+            RentRule::TenTimesDice if property.mortgaged || player == owner => 0,
+            RentRule::TenTimesDice => 10 * self.throw_dice(player),
         };
+        self.collect_rent(player, owner, position, rent);
+    }
+
+    /// Throws the dice for the player outside of their regular roll, returning the total.
+    ///
+    /// The throw doesn't move the player or count towards doubles.
+    #[instrument(skip(self))]
+    fn throw_dice(&mut self, player: PlayerId) -> usize {
+        let dice = self.dice.roll(&mut self.rng);
+        tracing::info!("{} threw {:?}", self.players[player].name, dice);
+        let total = dice.iter().sum();
+        let doubles = dice::is_doubles(&dice);
+        self.emit(GameEvent::DiceRolled {
+            player,
+            dice,
+            doubles,
+        });
+        total
+    }
+
+    /// Calculates the rent the player owes the owner for landing on the property.
+    fn rent_for(&self, player: PlayerId, owner: PlayerId, property: &Property) -> usize {
+        let owned_in_group = self.board.num_owned_in_group(owner, property.group);
+        property.rent_due(owned_in_group, self.players[player].spaces_to_move())
+    }
+
+    /// Makes the player pay the given rent to the owner of the property at the given position.
+    #[instrument(skip(self))]
+    fn collect_rent(
+        &mut self,
+        player: PlayerId,
+        owner: PlayerId,
+        position: CellIndex,
+        rent: usize,
+    ) {
+        if player == owner || rent == 0 {
+            return;
+        }
+
+        if let Some(property) = self.board.property_at(position) {
+            tracing::info!(
+                "{} owes {} ${} in rent for {}",
+                self.players[player].name,
+                self.players[owner].name,
+                rent,
+                property.name
+            );
+        }
+        self.charge_rent(player, owner, position, rent);
+    }
+
+    /// Offers the unowned property at the given position to the player at its list price.
+    ///
+    /// The game waits in [`TurnPhase::AwaitingPurchaseDecision`] for the player to decide.
+    fn offer_property(&mut self, player: PlayerId, position: CellIndex) {
+        self.tasks.push_back(Task::Offer { player, position });
+    }
+
+    /// Transfers the unowned property at the given position to the player for the given price.
+    fn buy_property(&mut self, player: PlayerId, position: CellIndex, price: usize) {
+        let property = self
+            .board
+            .property_at_mut(position)
+            .expect("only properties are bought");
+        property.owner = Some(player);
+        self.players[player].money -= price;
+        tracing::info!(
+            "{} bought {} for ${}",
+            self.players[player].name,
+            property.name,
+            price
+        );
+    }
+
+    /// Handles the property at the given position after it was declined at its list price.
+    ///
+    /// The property is auctioned if the rules allow it, otherwise it stays with the bank.
+    fn decline_property(&mut self, position: CellIndex) {
+        if self.rules.auction_declined_properties {
+            self.tasks.push_front(Task::Auction { position });
+        } else if let Some(property) = self.board.property_at(position) {
+            tracing::info!("{} remains with the bank", property.name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{brown_game, players, BALTIC_AVENUE, MEDITERRANEAN_AVENUE};
+
+    fn game() -> Game {
+        brown_game(players(), [0, 0])
+    }
+
+    fn buildings(game: &Game, position: CellIndex) -> usize {
+        game.board.property_at(position).unwrap().buildings
+    }
+
+    #[test]
+    fn buildings_must_be_even_across_the_group() {
+        let mut game = game();
+        game.buy_building(0, MEDITERRANEAN_AVENUE).unwrap();
+        assert_eq!(
+            game.buy_building(0, MEDITERRANEAN_AVENUE),
+            Err(BuildError::UnevenBuild)
+        );
+
+        game.buy_building(0, BALTIC_AVENUE).unwrap();
+        game.buy_building(0, MEDITERRANEAN_AVENUE).unwrap();
+        assert_eq!(
+            game.sell_building(0, BALTIC_AVENUE),
+            Err(BuildError::UnevenBuild)
+        );
+        assert_eq!(game.sell_building(0, MEDITERRANEAN_AVENUE), Ok(25));
+        assert_eq!(buildings(&game, MEDITERRANEAN_AVENUE), 1);
+        assert_eq!(buildings(&game, BALTIC_AVENUE), 1);
+    }
+
+    #[test]
+    fn hotels_swap_houses_with_the_bank() {
+        let mut game = game();
+        let money = game.players[0].money;
+        for _ in 0..HOTEL - 1 {
+            game.buy_building(0, MEDITERRANEAN_AVENUE).unwrap();
+            game.buy_building(0, BALTIC_AVENUE).unwrap();
+        }
+        assert_eq!(game.board.houses, 32 - 8);
+
+        game.buy_building(0, MEDITERRANEAN_AVENUE).unwrap();
+        assert_eq!(buildings(&game, MEDITERRANEAN_AVENUE), HOTEL);
+        assert_eq!(game.board.houses, 32 - 4);
+        assert_eq!(game.board.hotels, 12 - 1);
+        assert_eq!(game.players[0].money, money - 9 * 50);
+
+        assert_eq!(game.sell_building(0, MEDITERRANEAN_AVENUE), Ok(25));
+        assert_eq!(buildings(&game, MEDITERRANEAN_AVENUE), HOTEL - 1);
+        assert_eq!(game.board.houses, 32 - 8);
+        assert_eq!(game.board.hotels, 12);
+        assert_eq!(game.players[0].money, money - 9 * 50 + 25);
+    }
+
+    #[test]
+    fn bank_can_run_out_of_houses() {
+        let mut game = game();
+        game.board.houses = 0;
+        assert_eq!(
+            game.buy_building(0, MEDITERRANEAN_AVENUE),
+            Err(BuildError::NoHousesLeft)
+        );
+
+        // Selling a hotel needs four houses to put back on the property
+        game.board.houses = 8;
+        for _ in 0..HOTEL - 1 {
+            game.buy_building(0, MEDITERRANEAN_AVENUE).unwrap();
+            game.buy_building(0, BALTIC_AVENUE).unwrap();
+        }
+        game.buy_building(0, MEDITERRANEAN_AVENUE).unwrap();
+        game.board.houses = HOTEL - 2;
+        assert_eq!(
+            game.sell_building(0, MEDITERRANEAN_AVENUE),
+            Err(BuildError::NoHousesLeft)
+        );
+    }
+
+    #[test]
+    fn paused_games_do_not_step() {
+        // Rolling a 10 just visits jail
+        let mut game = Game::new(players())
+            .with_dice(ScriptedDice::new([(4, 6)]))
+            .with_event_queue();
+        game.step();
+        assert_eq!(game.current_player(), 1);
+        game.drain_events().for_each(drop);
+
+        game.pause();
+        game.step();
+        game.advance();
+        assert_eq!(game.state(), GameState::Paused);
+        assert_eq!(game.current_player(), 1);
+        assert_eq!(game.players[1].current_position, 0);
+        assert_eq!(game.drain_events().count(), 0);
+        assert_eq!(game.apply(Action::RollDice), Err(ActionError::NotRunning));
+
+        game.resume();
+        game.step();
+        assert_eq!(game.state(), GameState::Running);
+        assert_eq!(game.current_player(), 0);
+        assert_eq!(game.players[1].current_position, 10);
     }
 }
@@ -1,4 +1,4 @@
-use monopoly::{Game, Player};
+use monopoly::{AggressiveBuilder, ColorGroupCollector, Game, Player, RailroadHoarder, Rules};
 use tracing::{instrument, level_filters::LevelFilter, Level};
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, Layer, Registry};
@@ -27,10 +27,32 @@ fn setup_log() -> (WorkerGuard, WorkerGuard) {
 fn main() {
     let (_g1, _g2) = setup_log();
 
-    let mut game = Game::new(vec![Player::new("P1"), Player::new("P2")]);
-    for i in 0..100 {
-        tracing::info!("Turn {}", i);
-        game.advance();
+    let rules = Rules {
+        max_rounds: Some(1000),
+        ..Default::default()
+    };
+    let players = vec![
+        Player::with_strategy("P1", AggressiveBuilder::default()),
+        Player::with_strategy("P2", ColorGroupCollector::default()),
+        Player::with_strategy("P3", RailroadHoarder::default()),
+    ];
+    let mut game = Game::with_rules(players, rules);
+
+    // Replay a game by passing its seed
+    if let Some(seed) = std::env::args().nth(1) {
+        game = game.with_seed(seed.parse().expect("the seed is a number"));
     }
-    // game.run();
+    tracing::info!("Playing with seed {}", game.seed());
+
+    // Record the game, to reproduce it later
+    game = game.with_replay();
+
+    let outcome = game.run();
+    tracing::info!("{:#?}", outcome);
+
+    let file = std::fs::File::create("build/replay.json").expect("the build directory exists");
+    game.replay()
+        .expect("the game is recorded")
+        .save(std::io::BufWriter::new(file))
+        .expect("the replay can be written");
 }
@@ -0,0 +1,205 @@
+use std::cmp::Reverse;
+
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::{utils::PlayerId, Game, GameEvent, GameState};
+
+/// Why a game ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EndReason {
+    /// Every other player went bankrupt.
+    LastPlayerStanding,
+
+    /// The maximum number of rounds was played.
+    MaxRounds,
+
+    /// The game ran past its time limit.
+    TimeLimit,
+}
+
+/// A player's final standing in a game.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Standing {
+    /// The player's index in the turn order.
+    pub player: PlayerId,
+
+    /// The player's name.
+    pub name: String,
+
+    /// The player's final net worth.
+    pub net_worth: usize,
+
+    /// Used to determine if the player went bankrupt.
+    pub bankrupt: bool,
+}
+
+/// The result of a finished game.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameOutcome {
+    /// The index of the winning player in the turn order.
+    pub winner: PlayerId,
+
+    /// Why the game ended.
+    pub reason: EndReason,
+
+    /// The number of full rounds played.
+    pub rounds: usize,
+
+    /// Every player's final standing, from first to last place.
+    pub standings: Vec<Standing>,
+}
+
+impl Game {
+    /// Checks if the game is over, finishing it if it is.
+    ///
+    /// `round_over` should be set once every player has had their turn in the current round, so
+    /// the round and time limits are only checked between rounds.
+    pub(crate) fn check_for_end(&mut self, round_over: bool) {
+        let remaining = self
+            .players
+            .iter()
+            .filter(|player| !player.bankrupt)
+            .count();
+
+        let reason = if remaining <= 1 {
+            EndReason::LastPlayerStanding
+        } else if !round_over {
+            return;
+        } else if self
+            .rules
+            .max_rounds
+            .is_some_and(|max_rounds| self.rounds >= max_rounds)
+        {
+            EndReason::MaxRounds
+        } else if self
+            .rules
+            .time_limit
+            .zip(self.started_at)
+            .is_some_and(|(limit, started_at)| started_at.elapsed() >= limit)
+        {
+            EndReason::TimeLimit
+        } else {
+            return;
+        };
+
+        self.finish(reason);
+    }
+
+    /// Ends the game, ranking the players to determine the winner.
+    ///
+    /// Players still in the game are ranked by net worth (ties go to the player earlier in the
+    /// turn order), followed by bankrupt players in reverse order of elimination.
+    #[instrument(skip(self))]
+    fn finish(&mut self, reason: EndReason) {
+        let standing = |player: PlayerId| Standing {
+            player,
+            name: self.players[player].name.clone(),
+            net_worth: self.net_worth(player),
+            bankrupt: self.players[player].bankrupt,
+        };
+
+        let mut standings: Vec<Standing> = (0..self.players.len())
+            .filter(|&player| !self.players[player].bankrupt)
+            .map(standing)
+            .collect();
+        standings.sort_by_key(|standing| Reverse(standing.net_worth));
+        standings.extend(self.eliminated.iter().rev().map(|&player| standing(player)));
+
+        let outcome = GameOutcome {
+            winner: standings[0].player,
+            reason,
+            rounds: self.rounds,
+            standings,
+        };
+        tracing::info!(
+            "{} won the game after {} rounds ({:?})",
+            outcome.standings[0].name,
+            outcome.rounds,
+            reason
+        );
+
+        self.emit(GameEvent::GameOver {
+            winner: outcome.winner,
+            reason,
+        });
+        self.outcome = Some(outcome);
+        self.state = GameState::Finished;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Player, Rules, ScriptedDice};
+
+    /// A game of three where each player rolls the given dice on their turn, in order.
+    fn game(rolls: [(usize, usize); 3], max_rounds: Option<usize>) -> Game {
+        let players = vec![Player::new("P1"), Player::new("P2"), Player::new("P3")];
+        let rules = Rules {
+            max_rounds,
+            ..Default::default()
+        };
+        Game::with_rules(players, rules).with_dice(ScriptedDice::new(rolls))
+    }
+
+    /// Each standing as (player, net worth, bankrupt), from first to last place.
+    fn ranking(outcome: &GameOutcome) -> Vec<(PlayerId, usize, bool)> {
+        outcome
+            .standings
+            .iter()
+            .map(|standing| (standing.player, standing.net_worth, standing.bankrupt))
+            .collect()
+    }
+
+    // Rolling a 4 lands on income tax ($200), and rolling a 10 just visits jail
+    const TAX: (usize, usize) = (1, 3);
+    const VISIT: (usize, usize) = (4, 6);
+
+    #[test]
+    fn last_player_standing_wins() {
+        let mut game = game([TAX; 3], None);
+        game.players[0].money = 100;
+        game.players[1].money = 150;
+
+        let outcome = game.run();
+        assert_eq!(outcome.reason, EndReason::LastPlayerStanding);
+        assert_eq!(outcome.winner, 2);
+        assert_eq!(outcome.rounds, 0);
+        // The last player to go bankrupt places above the first
+        assert_eq!(
+            ranking(&outcome),
+            [(2, 1500, false), (1, 0, true), (0, 0, true)]
+        );
+    }
+
+    #[test]
+    fn max_rounds_ties_go_to_the_earlier_player() {
+        let mut game = game([TAX, VISIT, VISIT], Some(1));
+
+        let outcome = game.run();
+        assert_eq!(outcome.reason, EndReason::MaxRounds);
+        assert_eq!(outcome.winner, 1);
+        assert_eq!(outcome.rounds, 1);
+        assert_eq!(
+            ranking(&outcome),
+            [(1, 1500, false), (2, 1500, false), (0, 1300, false)]
+        );
+    }
+
+    #[test]
+    fn standings_rank_by_net_worth_then_elimination() {
+        let mut game = game([TAX, VISIT, VISIT], Some(1));
+        game.players[0].money = 100;
+        game.players[2].money = 2000;
+
+        let outcome = game.run();
+        assert_eq!(outcome.reason, EndReason::MaxRounds);
+        assert_eq!(outcome.winner, 2);
+        assert_eq!(
+            ranking(&outcome),
+            [(2, 2000, false), (1, 1500, false), (0, 0, true)]
+        );
+        assert_eq!(game.state(), GameState::Finished);
+    }
+}
@@ -0,0 +1,394 @@
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::{
+    property::HOTEL,
+    turn::Task,
+    utils::{CellIndex, PlayerId},
+    Game, GameEvent,
+};
+
+/// Who a debt is owed to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Creditor {
+    /// The bank.
+    Bank,
+
+    /// The bank, with the money going to free parking.
+    FreeParking,
+
+    /// Another player.
+    Player(PlayerId),
+}
+
+impl Game {
+    /// Makes the player pay the given amount to the bank.
+    pub(crate) fn pay_bank(&mut self, payer: PlayerId, amount: usize) {
+        self.charge(payer, amount, Creditor::Bank);
+    }
+
+    /// Makes the player pay the given tax or fine, which goes to free parking if the free parking
+    /// pot is in play and to the bank otherwise.
+    pub(crate) fn pay_fine(&mut self, payer: PlayerId, amount: usize) {
+        let creditor = self.fine_creditor();
+        self.charge(payer, amount, creditor);
+    }
+
+    /// Who taxes and fines are paid to.
+    pub(crate) fn fine_creditor(&self) -> Creditor {
+        if self.rules.free_parking_pot {
+            Creditor::FreeParking
+        } else {
+            Creditor::Bank
+        }
+    }
+
+    /// The name of the creditor, for logging.
+    pub(crate) fn creditor_name(&self, creditor: &Creditor) -> &str {
+        match creditor {
+            Creditor::Bank => "the bank",
+            Creditor::FreeParking => "free parking",
+            Creditor::Player(player) => &self.players[*player].name,
+        }
+    }
+
+    /// Makes the player pay the given amount to another player.
+    pub(crate) fn pay_player(&mut self, payer: PlayerId, payee: PlayerId, amount: usize) {
+        if payer == payee {
+            return;
+        }
+        self.charge(payer, amount, Creditor::Player(payee));
+    }
+
+    /// Makes the player pay the given rent to the owner of the property at the given position.
+    pub(crate) fn charge_rent(
+        &mut self,
+        player: PlayerId,
+        owner: PlayerId,
+        position: CellIndex,
+        amount: usize,
+    ) {
+        if player == owner || self.players[player].bankrupt || amount == 0 {
+            return;
+        }
+        self.tasks.push_back(Task::Payment {
+            debtor: player,
+            creditor: Creditor::Player(owner),
+            amount,
+            rent: Some(position),
+        });
+    }
+
+    /// Makes every other player still in the game pay the given amount to the payee.
+    ///
+    /// Each player settles their own debt, so one going bankrupt doesn't affect the others.
+    #[instrument(skip(self))]
+    pub(crate) fn collect_from_all(&mut self, payee: PlayerId, amount: usize) {
+        for other in self.active_opponents(payee) {
+            self.pay_player(other, payee, amount);
+        }
+    }
+
+    /// Makes the payer pay the given amount to every other player still in the game.
+    ///
+    /// The payments are made in turn order. If the payer can't raise enough for one of them, they
+    /// go bankrupt to that player.
+    #[instrument(skip(self))]
+    pub(crate) fn pay_each(&mut self, payer: PlayerId, amount: usize) {
+        for other in self.active_opponents(payer) {
+            self.pay_player(payer, other, amount);
+        }
+    }
+
+    /// Gets every other player that hasn't gone bankrupt, in turn order.
+    fn active_opponents(&self, player: PlayerId) -> Vec<PlayerId> {
+        (0..self.players.len())
+            .filter(|&other| other != player && !self.players[other].bankrupt)
+            .collect()
+    }
+
+    /// Queues a payment of the given amount from the debtor to the creditor.
+    ///
+    /// If the debtor can't cover the payment in cash when it comes up, the game waits in
+    /// [`TurnPhase::DebtSettlement`](crate::TurnPhase::DebtSettlement) until they raise the money
+    /// or go bankrupt.
+    #[instrument(skip(self))]
+    fn charge(&mut self, debtor: PlayerId, amount: usize, creditor: Creditor) {
+        if self.players[debtor].bankrupt || amount == 0 {
+            return;
+        }
+        self.tasks.push_back(Task::Payment {
+            debtor,
+            creditor,
+            amount,
+            rent: None,
+        });
+    }
+
+    /// Makes the debtor pay the given amount to the creditor out of their cash, as rent for the
+    /// property at `rent` if it's set.
+    ///
+    /// Returns `false` if the debtor doesn't have enough money, in which case nothing is paid.
+    pub(crate) fn pay(
+        &mut self,
+        debtor: PlayerId,
+        creditor: &Creditor,
+        amount: usize,
+        rent: Option<CellIndex>,
+    ) -> bool {
+        if self.players[debtor].withdraw(amount).is_err() {
+            return false;
+        }
+        self.pay_creditor(creditor, amount);
+        self.emit(match (rent, creditor) {
+            (Some(position), &Creditor::Player(owner)) => GameEvent::RentPaid {
+                player: debtor,
+                owner,
+                position,
+                amount,
+            },
+            _ => GameEvent::Paid {
+                player: debtor,
+                to: *creditor,
+                amount,
+            },
+        });
+        tracing::info!(
+            "{} paid {} ${}",
+            self.players[debtor].name,
+            self.creditor_name(creditor),
+            amount
+        );
+        true
+    }
+
+    /// Gives the creditor the given amount.
+    fn pay_creditor(&mut self, creditor: &Creditor, amount: usize) {
+        match creditor {
+            Creditor::Bank => {}
+            Creditor::FreeParking => self.board.add_to_free_parking(amount),
+            Creditor::Player(creditor) => self.players[*creditor].receive(amount),
+        }
+    }
+
+    /// Removes an insolvent player from the game, handing all of their assets to the creditor.
+    ///
+    /// Any remaining buildings are sold back to the bank first. Properties owed to the bank are
+    /// unmortgaged and queued for auction, and "Get out of jail free" cards go back to their decks.
+    #[instrument(skip(self))]
+    pub(crate) fn declare_bankruptcy(&mut self, debtor: PlayerId, creditor: Creditor) {
+        let properties = self.board.owned_by(debtor);
+        for &position in &properties {
+            let refund = self.clear_buildings(position);
+            self.players[debtor].receive(refund);
+        }
+
+        let player = &mut self.players[debtor];
+        player.bankrupt = true;
+        let money = std::mem::take(&mut player.money);
+        let jail_cards = std::mem::take(&mut player.jail_cards);
+        tracing::info!("{} went bankrupt", player.name);
+        self.eliminated.push(debtor);
+        self.emit(GameEvent::Bankrupt {
+            player: debtor,
+            creditor,
+        });
+
+        self.pay_creditor(&creditor, money);
+        match creditor {
+            Creditor::Player(creditor) => {
+                self.players[creditor].jail_cards.extend(jail_cards);
+                for position in properties {
+                    self.transfer_property(creditor, position);
+                }
+            }
+            Creditor::Bank | Creditor::FreeParking => {
+                for card in jail_cards {
+                    self.board.return_jail_card(card);
+                }
+                for position in properties {
+                    if let Some(property) = self.board.property_at_mut(position) {
+                        property.owner = None;
+                        property.mortgaged = false;
+                    }
+                    self.tasks.push_back(Task::Auction { position });
+                }
+            }
+        }
+    }
+
+    /// Returns all buildings on the property to the bank, returning half their cost.
+    fn clear_buildings(&mut self, position: CellIndex) -> usize {
+        let Some(property) = self.board.property_at_mut(position) else {
+            return 0;
+        };
+        let buildings = std::mem::take(&mut property.buildings);
+        let refund = buildings * property.building.1 / 2;
+        if buildings == HOTEL {
+            self.board.hotels += 1;
+        } else {
+            self.board.houses += buildings;
+        }
+        refund
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        board::{CommunityChestCard, JailCard},
+        testing::{give, BALTIC_AVENUE, MEDITERRANEAN_AVENUE},
+        Action, Player, TurnPhase,
+    };
+
+    /// A game of three where the first player owns the brown group, with the given amount of cash.
+    fn game(money: usize) -> Game {
+        let players = vec![Player::new("P1"), Player::new("P2"), Player::new("P3")];
+        let mut game = Game::new(players);
+        give(&mut game, 0, MEDITERRANEAN_AVENUE);
+        give(&mut game, 0, BALTIC_AVENUE);
+        game.players[0].money = money;
+        game
+    }
+
+    /// Starts settling a debt of the given amount from the first player to the creditor.
+    fn owe(game: &mut Game, amount: usize, creditor: Creditor) {
+        game.charge(0, amount, creditor);
+        game.continue_turn();
+        assert_eq!(
+            game.phase(),
+            TurnPhase::DebtSettlement { debtor: 0, amount }
+        );
+    }
+
+    /// Mortgages the whole brown group, so the first player has nothing left to raise money with.
+    fn mortgage_all(game: &mut Game) {
+        for position in [MEDITERRANEAN_AVENUE, BALTIC_AVENUE] {
+            game.board.property_at_mut(position).unwrap().mortgaged = true;
+        }
+    }
+
+    #[test]
+    fn selling_and_mortgaging_pays_off_the_debt() {
+        let mut game = game(0);
+        for position in [MEDITERRANEAN_AVENUE, BALTIC_AVENUE] {
+            game.board.property_at_mut(position).unwrap().buildings = 1;
+        }
+        game.board.houses -= 2;
+        owe(&mut game, 100, Creditor::Bank);
+
+        // Both houses sell for $25 and both properties mortgage for $30
+        while game.legal_actions() != [Action::PayDebt] {
+            let action = game.legal_actions().remove(0);
+            assert_ne!(action, Action::DeclareBankruptcy);
+            game.apply(action).unwrap();
+        }
+        game.apply(Action::PayDebt).unwrap();
+
+        assert_eq!(game.players[0].money, 10);
+        assert_eq!(game.board.houses, 32);
+        for position in [MEDITERRANEAN_AVENUE, BALTIC_AVENUE] {
+            let property = game.board.property_at(position).unwrap();
+            assert_eq!(property.buildings, 0);
+            assert!(property.mortgaged);
+            assert_eq!(property.owner, Some(0));
+        }
+        assert_eq!(game.phase(), TurnPhase::PreRoll);
+    }
+
+    #[test]
+    fn bankruptcy_to_a_player_hands_over_everything() {
+        let mut game = game(5);
+        mortgage_all(&mut game);
+        game.players[0].jail_cards.push(JailCard::Chance);
+        owe(&mut game, 100, Creditor::Player(1));
+        assert_eq!(game.legal_actions(), [Action::DeclareBankruptcy]);
+
+        game.apply(Action::DeclareBankruptcy).unwrap();
+        assert!(game.players[0].bankrupt);
+        assert_eq!(game.players[0].money, 0);
+        assert_eq!(game.players[1].money, 1505);
+        assert_eq!(game.players[1].jail_cards, [JailCard::Chance]);
+        assert!(game.players[0].jail_cards.is_empty());
+        assert_eq!(
+            game.board.owned_by(1),
+            [MEDITERRANEAN_AVENUE, BALTIC_AVENUE]
+        );
+
+        // The new owner decides about each mortgage in turn
+        assert_eq!(
+            game.phase(),
+            TurnPhase::MortgagedTransfer {
+                player: 1,
+                position: MEDITERRANEAN_AVENUE
+            }
+        );
+        game.apply(Action::KeepMortgaged).unwrap();
+        assert_eq!(
+            game.phase(),
+            TurnPhase::MortgagedTransfer {
+                player: 1,
+                position: BALTIC_AVENUE
+            }
+        );
+        game.apply(Action::KeepMortgaged).unwrap();
+        assert_eq!(game.phase(), TurnPhase::PreRoll);
+    }
+
+    #[test]
+    fn bankruptcy_to_the_bank_auctions_the_properties() {
+        let mut game = game(5);
+        mortgage_all(&mut game);
+        while game.board.draw_community_chest_card(&mut game.rng)
+            != CommunityChestCard::GetOutOfJailFree
+        {}
+        game.players[0].jail_cards.push(JailCard::CommunityChest);
+        owe(&mut game, 100, Creditor::Bank);
+
+        game.apply(Action::DeclareBankruptcy).unwrap();
+        assert!(game.board.owned_by(0).is_empty());
+        for position in [MEDITERRANEAN_AVENUE, BALTIC_AVENUE] {
+            let property = game.board.property_at(position).unwrap();
+            assert_eq!(property.owner, None);
+            assert!(!property.mortgaged);
+        }
+        assert!(matches!(
+            game.phase(),
+            TurnPhase::Auction {
+                position: MEDITERRANEAN_AVENUE,
+                ..
+            }
+        ));
+        assert!(matches!(
+            game.tasks.front(),
+            Some(Task::Auction {
+                position: BALTIC_AVENUE
+            })
+        ));
+
+        // The card can be drawn again
+        assert!((0..16).any(|_| {
+            game.board.draw_community_chest_card(&mut game.rng)
+                == CommunityChestCard::GetOutOfJailFree
+        }));
+    }
+
+    #[test]
+    fn bankrupt_players_leave_the_turn_order() {
+        let mut game = game(5);
+        mortgage_all(&mut game);
+        owe(&mut game, 100, Creditor::Player(2));
+        game.apply(Action::DeclareBankruptcy).unwrap();
+        game.apply(Action::KeepMortgaged).unwrap();
+        game.apply(Action::KeepMortgaged).unwrap();
+
+        assert_eq!(game.eliminated, [0]);
+        assert_eq!(game.current_player(), 1);
+        game.current_player = 2;
+        game.next_player();
+        assert_eq!(game.current_player(), 1);
+        assert_eq!(game.rounds, 1);
+        assert!(game.outcome().is_none());
+    }
+}
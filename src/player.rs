@@ -1,18 +1,54 @@
-use rand::Rng;
+use std::{fmt::Display, sync::Arc};
+
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
 use tracing::instrument;
 
-use crate::{board::NUM_CELLS, property::Property, utils::RcCell};
+use crate::{
+    board::{JailCard, NUM_CELLS},
+    dice::{is_doubles, Dice},
+    strategy::{DefaultStrategy, Strategy},
+};
+
+/// The ways a player can try to get out of jail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JailAction {
+    /// Pay the $50 bail.
+    PayBail,
 
-#[derive(Debug, Clone)]
+    /// Use a "Get out of jail free" card.
+    UseCard,
+
+    /// Try to roll doubles.
+    RollDoubles,
+}
+
+/// The player doesn't have enough money to make a payment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InsufficientFunds {
+    /// The amount the player needed to pay.
+    pub needed: usize,
+
+    /// The amount the player has.
+    pub available: usize,
+}
+
+impl Display for InsufficientFunds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!(
+            "Needed ${} but only ${} is available",
+            self.needed, self.available
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
     /// The player's name/id.
     pub(crate) name: String,
 
-    /// The properties a player owns.
-    pub(crate) properties: Vec<RcCell<Property>>,
-
     /// The last dice rolled by the player.
-    pub(crate) last_dice: Option<(usize, usize)>,
+    pub(crate) last_dice: Option<Vec<usize>>,
 
     /// The player's current amount of money.
     pub(crate) money: usize,
@@ -23,47 +59,101 @@ pub struct Player {
     /// Used to determine if the player is just visiting jail or in it.
     pub(crate) in_jail: bool,
 
-    /// Used to determine if the player owns the "Get out of jail free" card.
-    pub(crate) get_out_of_jail_free: bool,
+    /// The number of turns the player has failed to roll their way out of jail.
+    pub(crate) jail_turns: usize,
+
+    /// The "Get out of jail free" cards the player holds.
+    pub(crate) jail_cards: Vec<JailCard>,
+
+    /// Used to determine if the player has gone bankrupt and left the game.
+    pub(crate) bankrupt: bool,
+
+    /// Makes the player's decisions.
+    #[serde(skip, default = "crate::save::default_strategy")]
+    pub(crate) strategy: Arc<dyn Strategy>,
 }
 
 impl Player {
+    /// Creates a player that makes their decisions with the [`DefaultStrategy`].
     pub fn new(name: &str) -> Player {
+        Self::with_strategy(name, DefaultStrategy)
+    }
+
+    /// Creates a player that makes their decisions with the given strategy.
+    pub fn with_strategy(name: &str, strategy: impl Strategy + 'static) -> Player {
         Player {
             name: name.into(),
-            properties: vec![],
             last_dice: None,
             money: 1500,
             current_position: 0,
             in_jail: false,
-            get_out_of_jail_free: false,
+            jail_turns: 0,
+            jail_cards: vec![],
+            bankrupt: false,
+            strategy: Arc::new(strategy),
         }
     }
 
-    /// Rolls the player's dice and stores the output in `last_dice`.
-    fn roll_dice(&mut self) {
-        let die1 = rand::thread_rng().gen_range(1..=6);
-        let die2 = rand::thread_rng().gen_range(1..=6);
-        self.last_dice = Some((die1, die2));
+    /// Adds the given amount to the player's money.
+    pub(crate) fn receive(&mut self, amount: usize) {
+        self.money += amount;
+    }
+
+    /// Takes the given amount from the player's money.
+    ///
+    /// Nothing is taken if the player can't afford the whole amount.
+    pub(crate) fn withdraw(&mut self, amount: usize) -> Result<(), InsufficientFunds> {
+        if amount > self.money {
+            return Err(InsufficientFunds {
+                needed: amount,
+                available: self.money,
+            });
+        }
+        self.money -= amount;
+        Ok(())
+    }
+
+    /// Rolls the given dice and stores the output in `last_dice`.
+    #[instrument(skip(self, dice, rng))]
+    pub(crate) fn roll_dice(&mut self, dice: &mut dyn Dice, rng: &mut dyn RngCore) {
+        let rolled = dice.roll(rng);
+        tracing::info!("{} rolled {:?}", self.name, rolled);
+        self.last_dice = Some(rolled);
+    }
+
+    /// Checks if the player's last dice roll was a double (every die showing the same value).
+    ///
+    /// A single die never rolls doubles.
+    pub(crate) fn rolled_doubles(&self) -> bool {
+        self.last_dice.as_deref().is_some_and(is_doubles)
     }
 
     /// Combines the player's last dice rolls to get the number of spaces to move.
-    fn spaces_to_move(&self) -> usize {
-        let last_dice = self.last_dice.unwrap();
-        last_dice.0 + last_dice.1
+    pub(crate) fn spaces_to_move(&self) -> usize {
+        self.last_dice.as_ref().unwrap().iter().sum()
+    }
+
+    /// Moves the player forward by the given number of spaces.
+    ///
+    /// Returns `true` if the player passed or landed on "Go".
+    pub(crate) fn move_forward(&mut self, spaces: usize) -> bool {
+        let next_space = self.current_position + spaces;
+        self.current_position = next_space % NUM_CELLS;
+        next_space >= NUM_CELLS
     }
 
-    /// Gets the next position on the board, given the player's last dice roll;.
-    fn get_next_position(&self) -> usize {
-        let next_space = self.current_position + self.spaces_to_move();
-        next_space % NUM_CELLS
+    /// Moves the player forward until they reach the given position.
+    ///
+    /// Returns `true` if the player passed or landed on "Go".
+    pub(crate) fn move_forward_to(&mut self, position: usize) -> bool {
+        let spaces = (position + NUM_CELLS - self.current_position) % NUM_CELLS;
+        self.move_forward(spaces)
     }
 
-    /// Rolls the dice and moves the player to the new position.
-    #[instrument(skip(self))]
-    pub(crate) fn roll_and_move(&mut self) {
-        self.roll_dice();
-        self.current_position = self.get_next_position();
-        tracing::info!("{} rolled {:?}", self.name, self.last_dice.unwrap());
+    /// Moves the player forward by their last dice roll.
+    ///
+    /// Returns `true` if the player passed or landed on "Go".
+    pub(crate) fn move_by_dice(&mut self) -> bool {
+        self.move_forward(self.spaces_to_move())
     }
 }
@@ -1,7 +1,14 @@
-use crate::{player::Player, utils::RcCell};
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::utils::PlayerId;
+
+/// The number of buildings that make up a hotel.
+pub(crate) const HOTEL: usize = 5;
 
 /// A property that can be bought, sold, traded, and auctioned.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct Property {
     /// Name of the property.
     pub(crate) name: String,
@@ -22,11 +29,85 @@ pub(crate) struct Property {
     pub(crate) rent: Rent,
 
     /// The owner of the property.
-    pub(crate) owner: Option<RcCell<Player>>,
+    pub(crate) owner: Option<PlayerId>,
+
+    /// The number of buildings on the property (a hotel counts as 5).
+    pub(crate) buildings: usize,
+
+    /// Used to determine if the property is mortgaged.
+    pub(crate) mortgaged: bool,
+}
+
+impl Property {
+    /// Checks if the given player owns this property.
+    pub(crate) fn is_owned_by(&self, player: PlayerId) -> bool {
+        self.owner == Some(player)
+    }
+
+    /// The 10% interest charged on the mortgage, rounded up.
+    pub(crate) fn mortgage_interest(&self) -> usize {
+        self.mortgage.div_ceil(10)
+    }
+
+    /// The amount needed to lift the mortgage (the principal plus 10% interest).
+    pub(crate) fn unmortgage_cost(&self) -> usize {
+        self.mortgage + self.mortgage_interest()
+    }
+
+    /// Calculates the rent owed for landing on this property.
+    ///
+    /// `owned_in_group` is the number of properties in this property's group held by the owner,
+    /// and `dice_total` is the total of the tenant's last dice roll.
+    ///
+    /// Mortgaged properties collect no rent.
+    pub(crate) fn rent_due(&self, owned_in_group: usize, dice_total: usize) -> usize {
+        if self.mortgaged {
+            return 0;
+        }
+
+        let monopoly = owned_in_group == self.group.size();
+        match self.rent {
+            Rent::Property {
+                base,
+                monopoly: monopoly_rent,
+                house1,
+                house2,
+                house3,
+                house4,
+                hotel,
+            } => match self.buildings {
+                0 if monopoly => monopoly_rent,
+                0 => base,
+                1 => house1,
+                2 => house2,
+                3 => house3,
+                4 => house4,
+                HOTEL.. => hotel,
+            },
+            Rent::Railroad {
+                owned1,
+                owned2,
+                owned3,
+                owned4,
+            } => match owned_in_group {
+                0 | 1 => owned1,
+                2 => owned2,
+                3 => owned3,
+                _ => owned4,
+            },
+            Rent::Utility {
+                base,
+                monopoly: monopoly_multiplier,
+            } => {
+                let multiplier = if monopoly { monopoly_multiplier } else { base };
+                multiplier * dice_total
+            }
+        }
+    }
 }
 
 /// Represents different types of rents.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) enum Rent {
     Property {
         base: usize,
@@ -51,8 +132,9 @@ pub(crate) enum Rent {
     },
 }
 
-#[derive(Debug)]
-pub(crate) enum PropertyGroup {
+/// The color groups (and railroads and utilities) properties belong to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PropertyGroup {
     Brown,
     LightBlue,
     Pink,
@@ -64,3 +146,201 @@ pub(crate) enum PropertyGroup {
     Railroad,
     Utility,
 }
+
+impl PropertyGroup {
+    /// The number of properties in the group.
+    pub fn size(&self) -> usize {
+        match self {
+            PropertyGroup::Brown | PropertyGroup::DarkBlue | PropertyGroup::Utility => 2,
+            PropertyGroup::Railroad => 4,
+            _ => 3,
+        }
+    }
+}
+
+/// The reasons buying or selling a building can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildError {
+    /// The position isn't a property.
+    NotAProperty,
+
+    /// The player doesn't own the property.
+    NotOwner,
+
+    /// The property can't be built on.
+    NotBuildable,
+
+    /// The player doesn't own every property in the group.
+    NoMonopoly,
+
+    /// Buildings must be bought and sold evenly across a group.
+    UnevenBuild,
+
+    /// The property already has a hotel.
+    FullyBuilt,
+
+    /// The property has no buildings to sell.
+    NoBuildings,
+
+    /// A property in the group is mortgaged.
+    Mortgaged,
+
+    /// The player can't afford the building.
+    InsufficientFunds,
+
+    /// The bank has run out of houses.
+    NoHousesLeft,
+
+    /// The bank has run out of hotels.
+    NoHotelsLeft,
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildError::NotAProperty => f.write_str("Position is not a property"),
+            BuildError::NotOwner => f.write_str("Player does not own the property"),
+            BuildError::NotBuildable => f.write_str("Property can't be built on"),
+            BuildError::NoMonopoly => f.write_str("Player does not own the whole group"),
+            BuildError::UnevenBuild => f.write_str("Buildings must be spread evenly in a group"),
+            BuildError::FullyBuilt => f.write_str("Property already has a hotel"),
+            BuildError::NoBuildings => f.write_str("Property has no buildings"),
+            BuildError::Mortgaged => f.write_str("A property in the group is mortgaged"),
+            BuildError::InsufficientFunds => f.write_str("Player can't afford the building"),
+            BuildError::NoHousesLeft => f.write_str("The bank has no houses left"),
+            BuildError::NoHotelsLeft => f.write_str("The bank has no hotels left"),
+        }
+    }
+}
+
+/// The reasons mortgaging or unmortgaging a property can fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MortgageError {
+    /// The position isn't a property.
+    NotAProperty,
+
+    /// The player doesn't own the property.
+    NotOwner,
+
+    /// The property is already mortgaged.
+    AlreadyMortgaged,
+
+    /// The property isn't mortgaged.
+    NotMortgaged,
+
+    /// A property in the group still has buildings on it.
+    HasBuildings,
+
+    /// The player can't afford to lift the mortgage.
+    InsufficientFunds,
+}
+
+impl Display for MortgageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MortgageError::NotAProperty => f.write_str("Position is not a property"),
+            MortgageError::NotOwner => f.write_str("Player does not own the property"),
+            MortgageError::AlreadyMortgaged => f.write_str("Property is already mortgaged"),
+            MortgageError::NotMortgaged => f.write_str("Property is not mortgaged"),
+            MortgageError::HasBuildings => {
+                f.write_str("Buildings in the group must be sold before mortgaging")
+            }
+            MortgageError::InsufficientFunds => {
+                f.write_str("Player can't afford to lift the mortgage")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn property(group: PropertyGroup, rent: Rent) -> Property {
+        Property {
+            name: format!("{:?}", group),
+            group,
+            price: 100,
+            mortgage: 50,
+            building: (
+                group != PropertyGroup::Railroad && group != PropertyGroup::Utility,
+                50,
+            ),
+            rent,
+            owner: Some(0),
+            buildings: 0,
+            mortgaged: false,
+        }
+    }
+
+    fn street() -> Property {
+        property(
+            PropertyGroup::Brown,
+            Rent::Property {
+                base: 2,
+                monopoly: 4,
+                house1: 10,
+                house2: 30,
+                house3: 90,
+                house4: 160,
+                hotel: 250,
+            },
+        )
+    }
+
+    fn railroad() -> Property {
+        property(
+            PropertyGroup::Railroad,
+            Rent::Railroad {
+                owned1: 25,
+                owned2: 50,
+                owned3: 100,
+                owned4: 200,
+            },
+        )
+    }
+
+    fn utility() -> Property {
+        property(
+            PropertyGroup::Utility,
+            Rent::Utility {
+                base: 4,
+                monopoly: 10,
+            },
+        )
+    }
+
+    #[test]
+    fn monopoly_doubles_unimproved_rent() {
+        let mut street = street();
+        assert_eq!(street.rent_due(1, 7), 2);
+        assert_eq!(street.rent_due(2, 7), 4);
+
+        street.buildings = 1;
+        assert_eq!(street.rent_due(2, 7), 10);
+        street.buildings = HOTEL;
+        assert_eq!(street.rent_due(2, 7), 250);
+    }
+
+    #[test]
+    fn railroad_rent_depends_on_railroads_owned() {
+        let railroad = railroad();
+        let rents: Vec<_> = (1..=4).map(|owned| railroad.rent_due(owned, 7)).collect();
+        assert_eq!(rents, [25, 50, 100, 200]);
+    }
+
+    #[test]
+    fn utility_rent_multiplies_dice_total() {
+        let utility = utility();
+        assert_eq!(utility.rent_due(1, 7), 28);
+        assert_eq!(utility.rent_due(2, 7), 70);
+    }
+
+    #[test]
+    fn mortgaged_properties_collect_no_rent() {
+        for mut property in [street(), railroad(), utility()] {
+            property.mortgaged = true;
+            assert_eq!(property.rent_due(property.group.size(), 7), 0);
+        }
+    }
+}
@@ -0,0 +1,294 @@
+use std::{
+    fmt::Display,
+    io::{Read, Write},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    save::check_version, utils::PlayerId, Action, ActionError, Game, GameEvent, GameOutcome,
+    GameState, Player, Rules, SaveError, SAVE_FORMAT_VERSION,
+};
+
+/// A decision made during a game, along with everything that happened because of it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayStep {
+    /// The player that made the decision.
+    pub player: PlayerId,
+
+    /// The action they took.
+    pub action: Action,
+
+    /// The events the action led to, in order.
+    pub events: Vec<GameEvent>,
+}
+
+/// A recording of a game: its seed, rules and players, plus every decision made in it.
+///
+/// Replaying the decisions on a game with the same seed plays it out the same way, as long as it
+/// rolls the default fair dice and has no time limit. Games are only recorded if they're started
+/// with [`Game::with_replay`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Replay {
+    /// The version of the save format the replay was written with.
+    pub version: u32,
+
+    /// The seed the game's random choices were made from.
+    pub seed: u64,
+
+    /// The rules the game was played with.
+    pub rules: Rules,
+
+    /// The players' names, in turn order.
+    pub players: Vec<String>,
+
+    /// Every decision made, in order.
+    pub steps: Vec<ReplayStep>,
+
+    /// The result of the game, if it finished.
+    pub outcome: Option<GameOutcome>,
+}
+
+/// The first point where a replayed game stopped matching its recording.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Divergence {
+    /// A different player was making the decision at the step.
+    DecisionMaker {
+        step: usize,
+        expected: PlayerId,
+        actual: PlayerId,
+    },
+
+    /// The recorded action was rejected at the step.
+    Rejected {
+        step: usize,
+        action: Action,
+        error: ActionError,
+    },
+
+    /// The action at the step led to different events.
+    Events {
+        step: usize,
+        expected: Vec<GameEvent>,
+        actual: Vec<GameEvent>,
+    },
+
+    /// Every step matched, but the game ended differently.
+    Outcome {
+        expected: Option<GameOutcome>,
+        actual: Option<GameOutcome>,
+    },
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Divergence::DecisionMaker {
+                step,
+                expected,
+                actual,
+            } => f.write_fmt(format_args!(
+                "Step {}: expected player {} to decide, but player {} is deciding",
+                step, expected, actual
+            )),
+            Divergence::Rejected {
+                step,
+                action,
+                error,
+            } => f.write_fmt(format_args!(
+                "Step {}: {:?} was rejected: {}",
+                step, action, error
+            )),
+            Divergence::Events {
+                step,
+                expected,
+                actual,
+            } => f.write_fmt(format_args!(
+                "Step {}: expected events {:?}, got {:?}",
+                step, expected, actual
+            )),
+            Divergence::Outcome { expected, actual } => f.write_fmt(format_args!(
+                "Expected the game to end with {:?}, got {:?}",
+                expected, actual
+            )),
+        }
+    }
+}
+
+impl std::error::Error for Divergence {}
+
+impl Replay {
+    /// Writes the replay to the writer as JSON.
+    pub fn save(&self, writer: impl Write) -> Result<(), SaveError> {
+        serde_json::to_writer_pretty(writer, self)?;
+        Ok(())
+    }
+
+    /// Reads a replay written by [`Replay::save`].
+    pub fn load(mut reader: impl Read) -> Result<Replay, SaveError> {
+        let mut json = String::new();
+        reader
+            .read_to_string(&mut json)
+            .map_err(|err| SaveError::Json(serde_json::Error::io(err)))?;
+        check_version(&json)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+
+    /// Replays every step on a new game, checking that each one plays out as recorded and that
+    /// the game ends the same way.
+    ///
+    /// Returns the replayed game, or the first point where it diverged from the recording.
+    pub fn verify(&self) -> Result<Game, Divergence> {
+        let game = self.game_at(self.steps.len())?;
+        if game.outcome() != self.outcome.as_ref() {
+            return Err(Divergence::Outcome {
+                expected: self.outcome.clone(),
+                actual: game.outcome().cloned(),
+            });
+        }
+        Ok(game)
+    }
+
+    /// Replays the first `steps` steps on a new game, checking that each one plays out as
+    /// recorded, e.g. to look at the game just before something odd happened.
+    ///
+    /// # Panics
+    ///
+    /// If there aren't that many steps.
+    pub fn game_at(&self, steps: usize) -> Result<Game, Divergence> {
+        let players = self.players.iter().map(|name| Player::new(name)).collect();
+        let mut game = Game::with_rules(players, self.rules.clone())
+            .with_seed(self.seed)
+            .with_replay();
+
+        for (step, recorded) in self.steps[..steps].iter().enumerate() {
+            let actual = game.decision_maker();
+            if actual != recorded.player {
+                return Err(Divergence::DecisionMaker {
+                    step,
+                    expected: recorded.player,
+                    actual,
+                });
+            }
+            if let Err(error) = game.apply(recorded.action.clone()) {
+                return Err(Divergence::Rejected {
+                    step,
+                    action: recorded.action.clone(),
+                    error,
+                });
+            }
+
+            let events = &game
+                .recording
+                .steps()
+                .and_then(<[ReplayStep]>::last)
+                .expect("applied actions are recorded")
+                .events;
+            if *events != recorded.events {
+                return Err(Divergence::Events {
+                    step,
+                    expected: recorded.events.clone(),
+                    actual: events.clone(),
+                });
+            }
+        }
+        Ok(game)
+    }
+}
+
+/// The decisions recorded for [`Game::replay`].
+///
+/// The recording isn't saved with the game, and clones of the game (e.g. to look ahead) start
+/// without one, so only games that are replayed pay for it.
+#[derive(Debug, Default)]
+pub(crate) struct Recording {
+    /// Every action applied so far, along with the events it led to, if the game is recorded.
+    steps: Option<Vec<ReplayStep>>,
+
+    /// The events emitted by the action being applied.
+    events: Vec<GameEvent>,
+}
+
+impl Clone for Recording {
+    fn clone(&self) -> Self {
+        Self::default()
+    }
+}
+
+impl Recording {
+    /// The recorded steps, if the game is recorded.
+    pub(crate) fn steps(&self) -> Option<&[ReplayStep]> {
+        self.steps.as_deref()
+    }
+
+    /// Starts recording the events of a new action.
+    pub(crate) fn start_step(&mut self) {
+        self.events.clear();
+    }
+
+    /// Records an event emitted by the action being applied.
+    pub(crate) fn record_event(&mut self, event: &GameEvent) {
+        if self.steps.is_some() {
+            self.events.push(event.clone());
+        }
+    }
+
+    /// Records the action, along with the events it led to, once it's been applied.
+    pub(crate) fn finish_step(&mut self, player: PlayerId, action: &Action) {
+        if let Some(steps) = &mut self.steps {
+            steps.push(ReplayStep {
+                player,
+                action: action.clone(),
+                events: std::mem::take(&mut self.events),
+            });
+        }
+    }
+
+    /// Forgets the last recorded step, when its action is undone.
+    pub(crate) fn undo_step(&mut self) {
+        if let Some(steps) = &mut self.steps {
+            steps.pop();
+        }
+    }
+
+    /// Takes the recorded steps, to carry them over to another copy of the game.
+    pub(crate) fn take_steps(&mut self) -> Option<Vec<ReplayStep>> {
+        self.steps.take()
+    }
+
+    /// Carries on the given recorded steps.
+    pub(crate) fn set_steps(&mut self, steps: Option<Vec<ReplayStep>>) {
+        self.steps = steps;
+    }
+}
+
+impl Game {
+    /// Records every decision made in the game, so it can be replayed with [`Game::replay`].
+    ///
+    /// # Panics
+    ///
+    /// If the game has already started, since replays play the game from the start.
+    pub fn with_replay(mut self) -> Self {
+        assert!(
+            self.state == GameState::Created,
+            "Games can only be recorded before they start"
+        );
+        self.recording.steps = Some(vec![]);
+        self
+    }
+
+    /// A recording of every decision made in the game so far, which can be saved and replayed, or
+    /// `None` if the game isn't recorded (see [`Game::with_replay`]).
+    ///
+    /// Only actions taken through [`Game::apply`] (and so [`Game::step`]) are recorded.
+    pub fn replay(&self) -> Option<Replay> {
+        Some(Replay {
+            version: SAVE_FORMAT_VERSION,
+            seed: self.seed,
+            rules: self.rules.clone(),
+            players: self.players.iter().map(|p| p.name.clone()).collect(),
+            steps: self.recording.steps()?.to_vec(),
+            outcome: self.outcome.clone(),
+        })
+    }
+}
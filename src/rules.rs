@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{auction::AuctionKind, deck::DeckMode};
+
+/// The configurable rules of a game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Rules {
+    /// Auction properties that are declined at their list price instead of leaving them with the
+    /// bank.
+    pub auction_declined_properties: bool,
+
+    /// The type of auction used when a property is auctioned.
+    pub auction_kind: AuctionKind,
+
+    /// The amount each bid in an open-outcry auction must beat the highest bid by.
+    pub min_bid_increment: usize,
+
+    /// Put taxes and fines into a pot that the next player to land on free parking collects,
+    /// instead of paying them to the bank.
+    pub free_parking_pot: bool,
+
+    /// End the game after this many rounds, with the richest player winning.
+    pub max_rounds: Option<usize>,
+
+    /// End the game once it has run for this long, with the richest player winning.
+    ///
+    /// The limit is checked at the end of each round.
+    pub time_limit: Option<Duration>,
+
+    /// What happens to chance and community chest cards once they're played.
+    pub deck_mode: DeckMode,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            auction_declined_properties: true,
+            auction_kind: AuctionKind::OpenOutcry,
+            min_bid_increment: 10,
+            free_parking_pot: true,
+            max_rounds: None,
+            time_limit: None,
+            deck_mode: DeckMode::Cycle,
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use std::{
+    fmt::Display,
+    io::{Read, Write},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{utils::PlayerId, DefaultStrategy, Dice, FairDice, Game, Strategy};
+
+/// The version of the save format written by [`Game::save`].
+///
+/// Bump this whenever the saved state changes in a way older versions of the crate can't read,
+/// and keep [`Game::load`] able to read the older versions.
+pub const SAVE_FORMAT_VERSION: u32 = 1;
+
+/// The reasons a game can't be saved or loaded.
+#[derive(Debug)]
+pub enum SaveError {
+    /// The save couldn't be written or read, or isn't valid JSON for a game.
+    Json(serde_json::Error),
+
+    /// The save was written by a newer version of the format than this crate can read.
+    UnsupportedVersion { version: u32 },
+}
+
+impl Display for SaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveError::Json(err) => err.fmt(f),
+            SaveError::UnsupportedVersion { version } => f.write_fmt(format_args!(
+                "Save format version {} is not supported (the latest is {})",
+                version, SAVE_FORMAT_VERSION
+            )),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(err: serde_json::Error) -> Self {
+        SaveError::Json(err)
+    }
+}
+
+/// The format version of a save, read before the rest of it.
+#[derive(Deserialize)]
+struct SaveHeader {
+    version: u32,
+}
+
+/// A saved game, as written to JSON.
+#[derive(Serialize)]
+struct SaveFileRef<'a> {
+    version: u32,
+    game: &'a Game,
+}
+
+/// A saved game, as read from JSON.
+#[derive(Deserialize)]
+struct SaveFile {
+    game: Game,
+}
+
+impl Game {
+    /// Writes the full state of the game to the writer as JSON: the players, property ownership
+    /// and buildings, the free parking pot, the order of both decks, the random number generator
+    /// and the turn phase.
+    ///
+    /// The players' strategies and the game's dice aren't saved; see [`Game::load`].
+    pub fn save(&self, writer: impl Write) -> Result<(), SaveError> {
+        let file = SaveFileRef {
+            version: SAVE_FORMAT_VERSION,
+            game: self,
+        };
+        serde_json::to_writer_pretty(writer, &file)?;
+        Ok(())
+    }
+
+    /// Reads a game written by [`Game::save`], which continues exactly where the saved game left
+    /// off.
+    ///
+    /// Every player makes their decisions with the [`DefaultStrategy`] and the game rolls two fair
+    /// six-sided dice, until they're replaced with [`Game::set_strategy`] and [`Game::set_dice`].
+    pub fn load(mut reader: impl Read) -> Result<Game, SaveError> {
+        let mut json = String::new();
+        reader
+            .read_to_string(&mut json)
+            .map_err(|err| SaveError::Json(serde_json::Error::io(err)))?;
+
+        check_version(&json)?;
+        let SaveFile { game } = serde_json::from_str(&json)?;
+        Ok(game)
+    }
+
+    /// Replaces the strategy the player makes their decisions with, e.g. after loading a game.
+    ///
+    /// # Panics
+    ///
+    /// If `player` isn't a valid index into the turn order.
+    pub fn set_strategy(&mut self, player: PlayerId, strategy: impl Strategy + 'static) {
+        self.players[player].strategy = Arc::new(strategy);
+    }
+
+    /// Replaces the game's dice, e.g. after loading a game.
+    ///
+    /// Unlike [`Game::with_dice`], this can be done at any point in the game.
+    pub fn set_dice(&mut self, dice: impl Dice + 'static) {
+        self.dice = Box::new(dice);
+    }
+}
+
+/// Checks that the save was written with a version of the format this crate can read.
+pub(crate) fn check_version(json: &str) -> Result<(), SaveError> {
+    let SaveHeader { version } = serde_json::from_str(json)?;
+    if version > SAVE_FORMAT_VERSION {
+        return Err(SaveError::UnsupportedVersion { version });
+    }
+    Ok(())
+}
+
+/// The strategy given to players loaded from a save.
+pub(crate) fn default_strategy() -> Arc<dyn Strategy> {
+    Arc::new(DefaultStrategy)
+}
+
+/// The dice given to games loaded from a save.
+pub(crate) fn default_dice() -> Box<dyn Dice> {
+    Box::new(FairDice::default())
+}
+
+/// Saves when the game started as the time it has been running for, so time limits carry over
+/// to the loaded game.
+pub(crate) mod elapsed {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        started_at: &Option<Instant>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        started_at
+            .map(|started_at| started_at.elapsed())
+            .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Instant>, D::Error> {
+        let elapsed = Option::<Duration>::deserialize(deserializer)?;
+        Ok(elapsed.and_then(|elapsed| Instant::now().checked_sub(elapsed)))
+    }
+}
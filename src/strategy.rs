@@ -0,0 +1,410 @@
+use std::fmt::Debug;
+
+use crate::{
+    board::NUM_CELLS,
+    utils::{CellIndex, PlayerId},
+    Action, AuctionKind, Bid, Game, JailAction, PropertyGroup, Rules, Trade, TurnPhase,
+};
+
+/// Makes a player's decisions.
+///
+/// Whenever [`Game::step`] needs a decision, it asks the strategy of the player it's waiting on
+/// (see [`Game::decision_maker`]), giving it a read-only view of the game. Every method has a
+/// default, so a strategy only overrides the decisions it cares about.
+///
+/// Decisions the engine can't apply (e.g. buying a property the player can't afford) are logged
+/// and replaced with a safe choice: declining, dropping out, or moving on with the turn.
+pub trait Strategy: Debug + Send + Sync {
+    /// Decides whether to buy the property at its list price.
+    ///
+    /// By default, any property the player can afford is bought.
+    fn wants_to_buy(&self, view: &GameView, property: &PropertyInfo) -> bool {
+        view.money(view.player()) >= property.price
+    }
+
+    /// Decides how much to bid for the property in an auction, where the bid must be at least
+    /// `minimum`.
+    ///
+    /// By default, the property is valued at its list price: the bid is raised by the minimum in
+    /// open-outcry auctions until that value is reached, and the value is bid outright in
+    /// sealed-bid auctions.
+    fn bid(
+        &self,
+        view: &GameView,
+        property: &PropertyInfo,
+        kind: AuctionKind,
+        minimum: usize,
+    ) -> Bid {
+        let value = property.price.min(view.money(view.player()));
+        match kind {
+            AuctionKind::OpenOutcry if minimum <= value => Bid::Amount(minimum),
+            AuctionKind::SealedBid if minimum <= value => Bid::Amount(value),
+            _ => Bid::DropOut,
+        }
+    }
+
+    /// Decides how to try to get out of jail.
+    ///
+    /// By default, a "Get out of jail free" card is used if the player has one, otherwise they try
+    /// to roll doubles.
+    fn jail_action(&self, view: &GameView) -> JailAction {
+        if view.has_get_out_of_jail_free(view.player()) {
+            JailAction::UseCard
+        } else {
+            JailAction::RollDoubles
+        }
+    }
+
+    /// Decides whether to lift the mortgage on a mortgaged property the player was just given,
+    /// instead of only paying the 10% interest and keeping it mortgaged.
+    ///
+    /// A player who can't afford to lift the mortgage keeps it. By default, the mortgage is lifted
+    /// whenever the player can afford it.
+    fn wants_to_unmortgage(&self, view: &GameView, property: &PropertyInfo) -> bool {
+        view.money(view.player()) >= property.unmortgage_cost
+    }
+
+    /// Picks a building, selling, mortgaging or trading action to take before rolling the dice or
+    /// ending the turn, or `None` to move on.
+    ///
+    /// The engine keeps asking until `None` is returned (or a limit is reached for the turn). By
+    /// default, nothing is done.
+    fn manage_properties(&self, view: &GameView) -> Option<Action> {
+        None
+    }
+
+    /// Decides whether to accept a trade offered by `from`.
+    ///
+    /// The trade is described from the point of view of the player offering it, so `offered` is
+    /// what this player would receive. By default, every trade is declined.
+    fn respond_to_trade(&self, view: &GameView, from: PlayerId, trade: &Trade) -> bool {
+        false
+    }
+
+    /// Picks how to raise the money for a debt of `amount` the player can't cover: selling a
+    /// building, mortgaging a property, paying once there's enough, or declaring bankruptcy.
+    ///
+    /// By default, the first of the [legal actions](GameView::legal_actions) is taken: paying if
+    /// possible, otherwise selling buildings starting from the most expensive properties, then
+    /// mortgaging starting from the cheapest ones.
+    fn settle_debt(&self, view: &GameView, amount: usize) -> Action {
+        view.legal_actions().remove(0)
+    }
+}
+
+/// Makes the decisions described on each of the [`Strategy`] methods.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultStrategy;
+
+impl Strategy for DefaultStrategy {}
+
+/// A property as seen by a strategy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyInfo {
+    /// The property's position on the board.
+    pub position: CellIndex,
+
+    /// The name of the property.
+    pub name: String,
+
+    /// The group the property belongs to.
+    pub group: PropertyGroup,
+
+    /// The property's list price.
+    pub price: usize,
+
+    /// The amount received for mortgaging the property.
+    pub mortgage: usize,
+
+    /// The amount needed to lift the mortgage (the principal plus 10% interest).
+    pub unmortgage_cost: usize,
+
+    /// The cost of a house or hotel, if the property can be built on.
+    pub building_cost: Option<usize>,
+
+    /// The number of buildings on the property (5 for a hotel).
+    pub buildings: usize,
+
+    /// Whether the property is mortgaged.
+    pub mortgaged: bool,
+
+    /// The index of the owner in the turn order, if the property is owned.
+    pub owner: Option<PlayerId>,
+}
+
+/// A read-only view of the game, from the point of view of the player making a decision.
+///
+/// Players are referred to by their index in the turn order, and properties by their position on
+/// the board.
+#[derive(Debug, Clone, Copy)]
+pub struct GameView<'a> {
+    game: &'a Game,
+    player: PlayerId,
+}
+
+impl GameView<'_> {
+    /// The index of the player the view belongs to.
+    pub fn player(&self) -> PlayerId {
+        self.player
+    }
+
+    /// The index of the player whose turn it is.
+    pub fn current_player(&self) -> PlayerId {
+        self.game.current_player
+    }
+
+    /// The phase of the current player's turn.
+    pub fn phase(&self) -> TurnPhase {
+        self.game.phase
+    }
+
+    /// The actions the player the game is waiting on can take.
+    pub fn legal_actions(&self) -> Vec<Action> {
+        self.game.legal_actions()
+    }
+
+    /// The number of full rounds played.
+    pub fn rounds(&self) -> usize {
+        self.game.rounds
+    }
+
+    /// The rules the game is played with.
+    pub fn rules(&self) -> &Rules {
+        &self.game.rules
+    }
+
+    /// The number of players in the game, including those that went bankrupt.
+    pub fn num_players(&self) -> usize {
+        self.game.players.len()
+    }
+
+    /// The player's name.
+    pub fn name(&self, player: PlayerId) -> String {
+        self.game.players[player].name.clone()
+    }
+
+    /// The player's money.
+    pub fn money(&self, player: PlayerId) -> usize {
+        self.game.players[player].money
+    }
+
+    /// The player's net worth: their money plus the value of their properties and buildings.
+    pub fn net_worth(&self, player: PlayerId) -> usize {
+        self.game.net_worth(player)
+    }
+
+    /// The player's position on the board.
+    pub fn position(&self, player: PlayerId) -> CellIndex {
+        self.game.players[player].current_position
+    }
+
+    /// The player's last dice roll, if they've rolled.
+    pub fn last_dice(&self, player: PlayerId) -> Option<Vec<usize>> {
+        self.game.players[player].last_dice.clone()
+    }
+
+    /// Checks if the player is in jail (not just visiting).
+    pub fn in_jail(&self, player: PlayerId) -> bool {
+        self.game.players[player].in_jail
+    }
+
+    /// Checks if the player holds a "Get out of jail free" card.
+    pub fn has_get_out_of_jail_free(&self, player: PlayerId) -> bool {
+        !self.game.players[player].jail_cards.is_empty()
+    }
+
+    /// Checks if the player went bankrupt and left the game.
+    pub fn is_bankrupt(&self, player: PlayerId) -> bool {
+        self.game.players[player].bankrupt
+    }
+
+    /// The property at the given position, if there is one.
+    pub fn property(&self, position: CellIndex) -> Option<PropertyInfo> {
+        let property = self.game.board.property_at(position)?;
+        Some(PropertyInfo {
+            position,
+            name: property.name.clone(),
+            group: property.group,
+            price: property.price,
+            mortgage: property.mortgage,
+            unmortgage_cost: property.unmortgage_cost(),
+            building_cost: property.building.0.then_some(property.building.1),
+            buildings: property.buildings,
+            mortgaged: property.mortgaged,
+            owner: property.owner,
+        })
+    }
+
+    /// Every property on the board, in board order.
+    pub fn properties(&self) -> Vec<PropertyInfo> {
+        (0..NUM_CELLS)
+            .filter_map(|position| self.property(position))
+            .collect()
+    }
+
+    /// The properties the player owns, in board order.
+    pub fn properties_of(&self, player: PlayerId) -> Vec<PropertyInfo> {
+        self.properties()
+            .into_iter()
+            .filter(|property| property.owner == Some(player))
+            .collect()
+    }
+
+    /// The number of houses the bank has left.
+    pub fn houses_left(&self) -> usize {
+        self.game.board.houses
+    }
+
+    /// The number of hotels the bank has left.
+    pub fn hotels_left(&self) -> usize {
+        self.game.board.hotels
+    }
+}
+
+impl Game {
+    /// A read-only view of the game from the given player's point of view.
+    ///
+    /// # Panics
+    ///
+    /// If `player` isn't a valid index into the turn order.
+    pub fn view(&self, player: PlayerId) -> GameView<'_> {
+        assert!(player < self.players.len(), "no player at index {}", player);
+        GameView { game: self, player }
+    }
+
+    /// Asks the strategy of the player the game is waiting on for their next action.
+    ///
+    /// If `manage` is set, the strategy gets to manage its properties before rolling or ending
+    /// the turn.
+    pub(crate) fn strategy_action(&self, manage: bool) -> Action {
+        let idx = self.decision_maker();
+        let strategy = self.players[idx].strategy.clone();
+        let view = self.view(idx);
+
+        if manage && matches!(self.phase, TurnPhase::PreRoll | TurnPhase::PostRoll) {
+            if let Some(action) = strategy.manage_properties(&view) {
+                return action;
+            }
+        }
+
+        match self.phase {
+            TurnPhase::PreRoll if view.in_jail(idx) => match strategy.jail_action(&view) {
+                JailAction::PayBail => Action::PayBail,
+                JailAction::UseCard => Action::UseGetOutOfJailFree,
+                JailAction::RollDoubles => Action::RollDice,
+            },
+            TurnPhase::AwaitingPurchaseDecision { position } => {
+                let property = view
+                    .property(position)
+                    .expect("offered cells are properties");
+                if strategy.wants_to_buy(&view, &property) {
+                    Action::BuyProperty
+                } else {
+                    Action::DeclineProperty
+                }
+            }
+            TurnPhase::Auction {
+                position, minimum, ..
+            } => {
+                let property = view
+                    .property(position)
+                    .expect("auctioned cells are properties");
+                Action::Bid(strategy.bid(&view, &property, self.rules.auction_kind, minimum))
+            }
+            TurnPhase::AwaitingTradeResponse { from, .. } => {
+                let trade = self.trade.as_ref().expect("a trade is being offered");
+                if strategy.respond_to_trade(&view, from, trade) {
+                    Action::AcceptTrade
+                } else {
+                    Action::RejectTrade
+                }
+            }
+            TurnPhase::MortgagedTransfer { position, .. } => {
+                let property = view
+                    .property(position)
+                    .expect("transferred cells are properties");
+                if strategy.wants_to_unmortgage(&view, &property) {
+                    Action::Unmortgage(position)
+                } else {
+                    Action::KeepMortgaged
+                }
+            }
+            TurnPhase::DebtSettlement { amount, .. } => strategy.settle_debt(&view, amount),
+            _ => self.fallback_action(),
+        }
+    }
+
+    /// An action that's always legal for the player the game is waiting on, used when their
+    /// strategy's decision is rejected.
+    pub(crate) fn fallback_action(&self) -> Action {
+        match self.phase {
+            TurnPhase::AwaitingPurchaseDecision { .. } => Action::DeclineProperty,
+            TurnPhase::Auction { .. } => Action::Bid(Bid::DropOut),
+            TurnPhase::AwaitingTradeResponse { .. } => Action::RejectTrade,
+            TurnPhase::MortgagedTransfer { .. } => Action::KeepMortgaged,
+            TurnPhase::DebtSettlement { .. } => self.legal_actions().remove(0),
+            TurnPhase::PreRoll => Action::RollDice,
+            TurnPhase::PostRoll if self.roll_again => Action::RollDice,
+            TurnPhase::PostRoll | TurnPhase::Rolled | TurnPhase::EndTurn => Action::EndTurn,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{brown_game, BALTIC_AVENUE},
+        Player,
+    };
+
+    /// Offers $100 for Baltic Avenue while the other player owns it, and accepts every trade.
+    #[derive(Debug)]
+    struct Trader;
+
+    impl Strategy for Trader {
+        fn manage_properties(&self, view: &GameView) -> Option<Action> {
+            let other = 1 - view.player();
+            let baltic = view.property(BALTIC_AVENUE)?;
+            (baltic.owner == Some(other)).then(|| {
+                Action::ProposeTrade(Box::new(Trade {
+                    with: other,
+                    requested: vec![BALTIC_AVENUE],
+                    offered_money: 100,
+                    ..Default::default()
+                }))
+            })
+        }
+
+        fn respond_to_trade(&self, view: &GameView, from: PlayerId, trade: &Trade) -> bool {
+            true
+        }
+    }
+
+    fn game(other: Player) -> Game {
+        brown_game(vec![Player::with_strategy("P1", Trader), other], [0, 1])
+    }
+
+    #[test]
+    fn trades_are_answered_by_the_other_players_strategy() {
+        let mut game = game(Player::with_strategy("P2", Trader));
+        let action = game.strategy_action(true);
+        assert!(matches!(action, Action::ProposeTrade(_)));
+        game.apply(action).unwrap();
+
+        assert_eq!(game.strategy_action(true), Action::AcceptTrade);
+        game.apply(Action::AcceptTrade).unwrap();
+        assert_eq!(
+            game.board.property_at(BALTIC_AVENUE).unwrap().owner,
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn trades_are_declined_by_default() {
+        let mut game = game(Player::new("P2"));
+        game.apply(game.strategy_action(true)).unwrap();
+
+        assert_eq!(game.strategy_action(true), Action::RejectTrade);
+    }
+}
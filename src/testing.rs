@@ -0,0 +1,29 @@
+//! Setup shared by the unit tests.
+
+pub(crate) use crate::board::positions::{BALTIC_AVENUE, MEDITERRANEAN_AVENUE};
+use crate::{
+    utils::{CellIndex, PlayerId},
+    Game, Player,
+};
+
+/// Two players with the default strategy.
+pub(crate) fn players() -> Vec<Player> {
+    vec![Player::new("P1"), Player::new("P2")]
+}
+
+/// Starts a game where the brown group (Mediterranean and Baltic Avenue, $50 per building) is
+/// owned by the given players, in that order.
+pub(crate) fn brown_game(players: Vec<Player>, owners: [PlayerId; 2]) -> Game {
+    let mut game = Game::new(players);
+    give(&mut game, owners[0], MEDITERRANEAN_AVENUE);
+    give(&mut game, owners[1], BALTIC_AVENUE);
+    game
+}
+
+/// Hands the property at the position to the player, without paying for it.
+pub(crate) fn give(game: &mut Game, player: PlayerId, position: CellIndex) {
+    game.board
+        .property_at_mut(position)
+        .expect("position has a property")
+        .owner = Some(player);
+}
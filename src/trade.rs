@@ -0,0 +1,274 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::{
+    payment::Creditor,
+    utils::{CellIndex, PlayerId},
+    Game, GameEvent,
+};
+
+/// An offer to swap properties and money with another player.
+///
+/// The trade is proposed by the player the game is waiting on, so it only names the player it's
+/// offered to. Properties are referred to by their position on the board.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trade {
+    /// The index of the player the trade is offered to.
+    pub with: PlayerId,
+
+    /// The properties given away.
+    pub offered: Vec<CellIndex>,
+
+    /// The money given away.
+    pub offered_money: usize,
+
+    /// The properties asked for in return.
+    pub requested: Vec<CellIndex>,
+
+    /// The money asked for in return.
+    pub requested_money: usize,
+}
+
+/// The reasons a trade can be rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeError {
+    /// Players can't trade with themselves.
+    SamePlayer,
+
+    /// The other player isn't in the game or went bankrupt.
+    InvalidPartner,
+
+    /// Nothing would change hands.
+    Empty,
+
+    /// The property at the position isn't owned by the player giving it away.
+    NotOwner { position: CellIndex },
+
+    /// The property at the position is listed more than once.
+    Duplicate { position: CellIndex },
+
+    /// A property in the group of the property at the position still has buildings on it.
+    HasBuildings { position: CellIndex },
+
+    /// The player can't cover the money they'd give away.
+    InsufficientFunds { player: PlayerId, money: usize },
+}
+
+impl Display for TradeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TradeError::SamePlayer => f.write_str("Players can't trade with themselves"),
+            TradeError::InvalidPartner => f.write_str("The other player is not in the game"),
+            TradeError::Empty => f.write_str("The trade doesn't exchange anything"),
+            TradeError::NotOwner { position } => f.write_fmt(format_args!(
+                "The property at {} is not owned by the player trading it",
+                position
+            )),
+            TradeError::Duplicate { position } => f.write_fmt(format_args!(
+                "The property at {} is listed more than once",
+                position
+            )),
+            TradeError::HasBuildings { position } => f.write_fmt(format_args!(
+                "Buildings in the group of the property at {} must be sold before trading it",
+                position
+            )),
+            TradeError::InsufficientFunds { player, money } => {
+                f.write_fmt(format_args!("Player {} can't cover ${}", player, money))
+            }
+        }
+    }
+}
+
+impl Game {
+    /// Checks that the trade can be made between the player and the player it's offered to.
+    pub(crate) fn check_trade(&self, player: PlayerId, trade: &Trade) -> Result<(), TradeError> {
+        if trade.with == player {
+            return Err(TradeError::SamePlayer);
+        }
+        if self
+            .players
+            .get(trade.with)
+            .is_none_or(|partner| partner.bankrupt)
+        {
+            return Err(TradeError::InvalidPartner);
+        }
+        if trade.offered.is_empty()
+            && trade.requested.is_empty()
+            && trade.offered_money == 0
+            && trade.requested_money == 0
+        {
+            return Err(TradeError::Empty);
+        }
+
+        let sides = [
+            (player, &trade.offered, trade.offered_money),
+            (trade.with, &trade.requested, trade.requested_money),
+        ];
+        for (owner, properties, money) in sides {
+            for (i, &position) in properties.iter().enumerate() {
+                if properties[..i].contains(&position) {
+                    return Err(TradeError::Duplicate { position });
+                }
+                let property = self
+                    .board
+                    .property_at(position)
+                    .filter(|property| property.is_owned_by(owner))
+                    .ok_or(TradeError::NotOwner { position })?;
+                let group = self.board.properties_in_group(property.group);
+                if group.iter().any(|p| p.buildings > 0) {
+                    return Err(TradeError::HasBuildings { position });
+                }
+            }
+            if self.players[owner].money < money {
+                return Err(TradeError::InsufficientFunds {
+                    player: owner,
+                    money,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Swaps the properties and money in the trade between the player and the player it's offered
+    /// to, which must have been checked with [`Game::check_trade`].
+    ///
+    /// Mortgaged properties are handed over like in a bankruptcy, so their new owner decides
+    /// whether to lift the mortgage.
+    #[instrument(skip(self))]
+    pub(crate) fn make_trade(&mut self, player: PlayerId, trade: &Trade) {
+        tracing::info!(
+            "{} and {} traded",
+            self.players[player].name,
+            self.players[trade.with].name
+        );
+        if trade.offered_money > 0 {
+            self.pay(
+                player,
+                &Creditor::Player(trade.with),
+                trade.offered_money,
+                None,
+            );
+        }
+        if trade.requested_money > 0 {
+            self.pay(
+                trade.with,
+                &Creditor::Player(player),
+                trade.requested_money,
+                None,
+            );
+        }
+        for &position in &trade.offered {
+            self.transfer_property(trade.with, position);
+        }
+        for &position in &trade.requested {
+            self.transfer_property(player, position);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testing::{brown_game, players, BALTIC_AVENUE, MEDITERRANEAN_AVENUE},
+        Action, ActionError, TurnPhase,
+    };
+
+    fn game() -> Game {
+        brown_game(players(), [0, 1])
+    }
+
+    fn swap() -> Trade {
+        Trade {
+            with: 1,
+            offered: vec![MEDITERRANEAN_AVENUE],
+            offered_money: 50,
+            requested: vec![BALTIC_AVENUE],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn accepted_trade_swaps_properties_and_money() {
+        let mut game = game();
+        let money = (game.players[0].money, game.players[1].money);
+
+        game.apply(Action::ProposeTrade(Box::new(swap()))).unwrap();
+        assert_eq!(
+            game.phase(),
+            TurnPhase::AwaitingTradeResponse { from: 0, to: 1 }
+        );
+        assert_eq!(game.decision_maker(), 1);
+
+        game.apply(Action::AcceptTrade).unwrap();
+        assert_eq!(game.phase(), TurnPhase::PreRoll);
+        assert!(game
+            .board
+            .property_at(MEDITERRANEAN_AVENUE)
+            .unwrap()
+            .is_owned_by(1));
+        assert!(game
+            .board
+            .property_at(BALTIC_AVENUE)
+            .unwrap()
+            .is_owned_by(0));
+        assert_eq!(game.players[0].money, money.0 - 50);
+        assert_eq!(game.players[1].money, money.1 + 50);
+    }
+
+    #[test]
+    fn rejected_trade_changes_nothing() {
+        let mut game = game();
+        game.apply(Action::ProposeTrade(Box::new(swap()))).unwrap();
+        game.apply(Action::RejectTrade).unwrap();
+
+        assert_eq!(game.phase(), TurnPhase::PreRoll);
+        assert_eq!(game.decision_maker(), 0);
+        assert!(game
+            .board
+            .property_at(MEDITERRANEAN_AVENUE)
+            .unwrap()
+            .is_owned_by(0));
+        assert!(game
+            .board
+            .property_at(BALTIC_AVENUE)
+            .unwrap()
+            .is_owned_by(1));
+    }
+
+    #[test]
+    fn invalid_trades_are_rejected() {
+        let mut game = game();
+        let trade = Trade {
+            requested: vec![MEDITERRANEAN_AVENUE],
+            ..swap()
+        };
+        assert_eq!(
+            game.check_trade(0, &trade),
+            Err(TradeError::NotOwner {
+                position: MEDITERRANEAN_AVENUE
+            })
+        );
+        assert_eq!(
+            game.check_trade(0, &Trade { with: 0, ..swap() }),
+            Err(TradeError::SamePlayer)
+        );
+        assert_eq!(
+            game.apply(Action::ProposeTrade(Box::new(Trade {
+                with: 1,
+                ..Default::default()
+            }))),
+            Err(ActionError::Trade(TradeError::Empty))
+        );
+
+        game.board.property_at_mut(BALTIC_AVENUE).unwrap().buildings = 1;
+        assert_eq!(
+            game.check_trade(0, &swap()),
+            Err(TradeError::HasBuildings {
+                position: MEDITERRANEAN_AVENUE
+            })
+        );
+    }
+}
@@ -0,0 +1,799 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use crate::{
+    auction::Auction,
+    board::NUM_CELLS,
+    payment::Creditor,
+    player::{InsufficientFunds, JailAction},
+    utils::{CellIndex, PlayerId},
+    AuctionResult, Bid, BidError, BuildError, Game, GameEvent, GameState, MortgageError, RentRule,
+    Trade, TradeError, MAX_JAIL_TURNS,
+};
+
+/// The phases of a player's turn.
+///
+/// Most phases wait for the current player to act, but auctions, debts, trades and handed over
+/// mortgaged properties can also wait on other players (see [`Game::decision_maker`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TurnPhase {
+    /// Before rolling the dice.
+    ///
+    /// The player can build, sell buildings, (un)mortgage properties and propose trades. A jailed
+    /// player also chooses how to try to get out of jail.
+    PreRoll,
+
+    /// The dice were rolled and the player's move is being resolved.
+    ///
+    /// The engine moves through this phase on its own.
+    Rolled,
+
+    /// The player landed on the unowned property at `position` and must decide whether to buy it.
+    AwaitingPurchaseDecision { position: CellIndex },
+
+    /// The property at `position` is being auctioned and a bid of at least `minimum` is expected
+    /// from `bidder`.
+    Auction {
+        position: CellIndex,
+        bidder: PlayerId,
+        minimum: usize,
+    },
+
+    /// `from` offered `to` a trade and is waiting for an answer.
+    AwaitingTradeResponse { from: PlayerId, to: PlayerId },
+
+    /// `player` was handed the mortgaged property at `position` and must either lift the mortgage
+    /// or pay the 10% interest and keep it mortgaged.
+    MortgagedTransfer {
+        player: PlayerId,
+        position: CellIndex,
+    },
+
+    /// `debtor` owes `amount` but doesn't have the cash, so they must sell buildings and mortgage
+    /// properties to raise it, or declare bankruptcy.
+    DebtSettlement { debtor: PlayerId, amount: usize },
+
+    /// After moving.
+    ///
+    /// The player can build, sell buildings, (un)mortgage properties and propose trades, then
+    /// either rolls again (if they rolled doubles) or ends their turn.
+    PostRoll,
+
+    /// The player's turn is over and the next player is up.
+    ///
+    /// The engine moves through this phase on its own.
+    EndTurn,
+}
+
+/// Something done by the player the game is waiting on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Action {
+    /// Roll the dice and move. A jailed player rolls to try for doubles instead.
+    RollDice,
+
+    /// Pay bail to leave jail.
+    PayBail,
+
+    /// Use a "Get out of jail free" card to leave jail.
+    UseGetOutOfJailFree,
+
+    /// Buy the property that was landed on at its list price.
+    BuyProperty,
+
+    /// Decline the property that was landed on, which sends it to auction if the rules allow it.
+    DeclineProperty,
+
+    /// Bid in the running auction.
+    Bid(Bid),
+
+    /// Buy a house (or hotel) for the property at the given position.
+    BuyBuilding(CellIndex),
+
+    /// Sell a house (or hotel) on the property at the given position.
+    SellBuilding(CellIndex),
+
+    /// Mortgage the property at the given position.
+    Mortgage(CellIndex),
+
+    /// Lift the mortgage on the property at the given position, including one that was just handed
+    /// over.
+    Unmortgage(CellIndex),
+
+    /// Offer a trade to another player.
+    ProposeTrade(Box<Trade>),
+
+    /// Accept the trade that was offered.
+    AcceptTrade,
+
+    /// Turn down the trade that was offered.
+    RejectTrade,
+
+    /// Keep the mortgage on a property that was just handed over, paying the 10% interest.
+    KeepMortgaged,
+
+    /// Pay off the debt being settled, once enough money was raised.
+    PayDebt,
+
+    /// Give up on a debt that can't be paid, handing everything over to the creditor.
+    DeclareBankruptcy,
+
+    /// End the turn.
+    EndTurn,
+}
+
+/// The reasons an action can be rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActionError {
+    /// The game is paused or finished.
+    NotRunning,
+
+    /// The action can't be taken in the current phase.
+    NotAllowed { action: Action, phase: TurnPhase },
+
+    /// The building couldn't be bought or sold.
+    Build(BuildError),
+
+    /// The property couldn't be mortgaged or unmortgaged.
+    Mortgage(MortgageError),
+
+    /// The bid was rejected.
+    Bid(BidError),
+
+    /// The trade couldn't be made.
+    Trade(TradeError),
+
+    /// The player doesn't have enough money to buy the property or pay the debt.
+    InsufficientFunds(InsufficientFunds),
+
+    /// The player can't declare bankruptcy while they can still raise money.
+    CanStillRaiseMoney,
+}
+
+impl Display for ActionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ActionError::NotRunning => f.write_str("The game is not running"),
+            ActionError::NotAllowed { action, phase } => {
+                f.write_fmt(format_args!("{:?} is not allowed in {:?}", action, phase))
+            }
+            ActionError::Build(err) => err.fmt(f),
+            ActionError::Mortgage(err) => err.fmt(f),
+            ActionError::Bid(err) => err.fmt(f),
+            ActionError::Trade(err) => err.fmt(f),
+            ActionError::InsufficientFunds(err) => err.fmt(f),
+            ActionError::CanStillRaiseMoney => {
+                f.write_str("Buildings and properties must be sold or mortgaged first")
+            }
+        }
+    }
+}
+
+/// Work the engine still has to get through before the turn can continue.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) enum Task {
+    /// The debtor pays the creditor, as rent for the property at `rent` if it's set.
+    Payment {
+        debtor: PlayerId,
+        creditor: Creditor,
+        amount: usize,
+        rent: Option<CellIndex>,
+    },
+
+    /// The player is offered the unowned property at the position.
+    Offer {
+        player: PlayerId,
+        position: CellIndex,
+    },
+
+    /// The property at the position is auctioned, if it's still unowned.
+    Auction { position: CellIndex },
+
+    /// The player is let out of jail.
+    Release { player: PlayerId },
+
+    /// The player decides what to do with the mortgage on the property they were just handed.
+    MortgagedTransfer {
+        player: PlayerId,
+        position: CellIndex,
+    },
+
+    /// The player moves by their last dice roll and handles where they land.
+    MoveByDice { player: PlayerId },
+}
+
+impl Game {
+    /// The phase of the current player's turn.
+    pub fn phase(&self) -> TurnPhase {
+        self.phase
+    }
+
+    /// The index of the player the game is waiting on.
+    ///
+    /// This is the current player, except during auctions (the next bidder), debt settlement (the
+    /// debtor), trades (the player the trade is offered to) and mortgaged transfers (the new
+    /// owner).
+    pub fn decision_maker(&self) -> PlayerId {
+        match self.phase {
+            TurnPhase::Auction { bidder, .. } => bidder,
+            TurnPhase::AwaitingTradeResponse { to, .. } => to,
+            TurnPhase::MortgagedTransfer { player, .. } => player,
+            TurnPhase::DebtSettlement { debtor, .. } => debtor,
+            _ => self.current_player,
+        }
+    }
+
+    /// The actions [`Game::decision_maker`] can take in the current phase.
+    ///
+    /// During an auction, `Bid(Bid::Amount(minimum))` stands in for any bid from the minimum up to
+    /// the bidder's money. Trades aren't listed, but can be proposed whenever buildings can be
+    /// bought.
+    pub fn legal_actions(&self) -> Vec<Action> {
+        if !self.can_advance() {
+            return vec![];
+        }
+
+        let idx = self.decision_maker();
+        let player = &self.players[idx];
+        let mut actions = vec![];
+        match self.phase {
+            TurnPhase::PreRoll => {
+                actions.push(Action::RollDice);
+                if player.in_jail {
+                    actions.push(Action::PayBail);
+                    if !player.jail_cards.is_empty() {
+                        actions.push(Action::UseGetOutOfJailFree);
+                    }
+                }
+                actions.extend(self.property_actions(idx));
+            }
+            TurnPhase::AwaitingPurchaseDecision { position } => {
+                if self
+                    .board
+                    .property_at(position)
+                    .is_some_and(|property| player.money >= property.price)
+                {
+                    actions.push(Action::BuyProperty);
+                }
+                actions.push(Action::DeclineProperty);
+            }
+            TurnPhase::Auction { minimum, .. } => {
+                if player.money >= minimum {
+                    actions.push(Action::Bid(Bid::Amount(minimum)));
+                }
+                actions.push(Action::Bid(Bid::DropOut));
+            }
+            TurnPhase::AwaitingTradeResponse { .. } => {
+                actions.push(Action::AcceptTrade);
+                actions.push(Action::RejectTrade);
+            }
+            TurnPhase::MortgagedTransfer { position, .. } => {
+                if self.check_unmortgage(idx, position).is_ok() {
+                    actions.push(Action::Unmortgage(position));
+                }
+                actions.push(Action::KeepMortgaged);
+            }
+            TurnPhase::DebtSettlement { amount, .. } => {
+                let liquidation = self.liquidation_actions(idx);
+                if player.money >= amount {
+                    actions.push(Action::PayDebt);
+                } else if liquidation.is_empty() {
+                    actions.push(Action::DeclareBankruptcy);
+                }
+                actions.extend(liquidation);
+            }
+            TurnPhase::PostRoll => {
+                if self.roll_again {
+                    actions.push(Action::RollDice);
+                } else {
+                    actions.push(Action::EndTurn);
+                }
+                actions.extend(self.property_actions(idx));
+            }
+            TurnPhase::Rolled | TurnPhase::EndTurn => {}
+        }
+        actions
+    }
+
+    /// Takes the action on behalf of [`Game::decision_maker`], then runs the game until the next
+    /// decision is needed.
+    ///
+    /// Starts the game if it hasn't started yet.
+    #[instrument(skip(self))]
+    pub fn apply(&mut self, action: Action) -> Result<(), ActionError> {
+        if !self.can_advance() {
+            return Err(ActionError::NotRunning);
+        }
+        let before = self.undo_state();
+        self.recording.start_step();
+
+        let idx = self.decision_maker();
+        let managing = matches!(self.phase, TurnPhase::PreRoll | TurnPhase::PostRoll);
+        let settling = matches!(self.phase, TurnPhase::DebtSettlement { .. });
+        match (self.phase, action.clone()) {
+            (_, Action::BuyBuilding(position)) if managing => {
+                self.buy_building(idx, position)
+                    .map_err(ActionError::Build)?;
+            }
+            (_, Action::SellBuilding(position)) if managing || settling => {
+                self.sell_building(idx, position)
+                    .map_err(ActionError::Build)?;
+            }
+            (_, Action::Mortgage(position)) if managing || settling => {
+                self.mortgage(idx, position)
+                    .map_err(ActionError::Mortgage)?;
+            }
+            (_, Action::Unmortgage(position)) if managing => {
+                self.unmortgage(idx, position)
+                    .map_err(ActionError::Mortgage)?;
+            }
+            (_, Action::ProposeTrade(trade)) if managing => {
+                self.check_trade(idx, &trade).map_err(ActionError::Trade)?;
+                tracing::info!(
+                    "{} offered {} a trade",
+                    self.players[idx].name,
+                    self.players[trade.with].name
+                );
+                self.phase = TurnPhase::AwaitingTradeResponse {
+                    from: idx,
+                    to: trade.with,
+                };
+                self.emit(GameEvent::TradeProposed {
+                    player: idx,
+                    trade: trade.as_ref().clone(),
+                });
+                self.trade = Some(*trade);
+            }
+            (TurnPhase::AwaitingTradeResponse { from, .. }, Action::AcceptTrade) => {
+                let trade = self.trade.take().expect("a trade is being offered");
+                self.emit(GameEvent::TradeAccepted { player: idx, from });
+                self.make_trade(from, &trade);
+                self.continue_turn();
+            }
+            (TurnPhase::AwaitingTradeResponse { from, .. }, Action::RejectTrade) => {
+                self.trade = None;
+                tracing::info!("{} turned down the trade", self.players[idx].name);
+                self.emit(GameEvent::TradeRejected { player: idx, from });
+                self.continue_turn();
+            }
+            (TurnPhase::MortgagedTransfer { position, .. }, Action::Unmortgage(unmortgaged))
+                if unmortgaged == position =>
+            {
+                self.unmortgage(idx, position)
+                    .map_err(ActionError::Mortgage)?;
+                self.continue_turn();
+            }
+            (TurnPhase::MortgagedTransfer { position, .. }, Action::KeepMortgaged) => {
+                let interest = self
+                    .board
+                    .property_at(position)
+                    .expect("transferred cells are properties")
+                    .mortgage_interest();
+                self.pay_bank(idx, interest);
+                self.continue_turn();
+            }
+            (TurnPhase::PreRoll, Action::RollDice) => self.roll(),
+            (TurnPhase::PostRoll, Action::RollDice) if self.roll_again => self.roll(),
+            (TurnPhase::PreRoll, Action::PayBail) if self.players[idx].in_jail => {
+                self.pay_bail(idx);
+                self.continue_turn();
+            }
+            (TurnPhase::PreRoll, Action::UseGetOutOfJailFree)
+                if self.players[idx].in_jail && !self.players[idx].jail_cards.is_empty() =>
+            {
+                let player = &mut self.players[idx];
+                tracing::info!("{} used a \"Get out of jail free\" card", player.name);
+                let card = player.jail_cards.pop().expect("player has a card");
+                self.board.return_jail_card(card);
+                self.emit(GameEvent::JailCardUsed { player: idx });
+                self.release_from_jail(idx);
+            }
+            (TurnPhase::AwaitingPurchaseDecision { position }, Action::BuyProperty) => {
+                let price = self
+                    .board
+                    .property_at(position)
+                    .expect("offered cells are properties")
+                    .price;
+                let money = self.players[idx].money;
+                if money < price {
+                    return Err(ActionError::InsufficientFunds(InsufficientFunds {
+                        needed: price,
+                        available: money,
+                    }));
+                }
+                self.buy_property(idx, position, price);
+                self.emit(GameEvent::PropertyBought {
+                    player: idx,
+                    position,
+                    price,
+                });
+                self.continue_turn();
+            }
+            (TurnPhase::AwaitingPurchaseDecision { position }, Action::DeclineProperty) => {
+                if let Some(property) = self.board.property_at(position) {
+                    tracing::info!(
+                        "{} declined to buy {}",
+                        self.players[idx].name,
+                        property.name
+                    );
+                }
+                self.emit(GameEvent::PropertyDeclined {
+                    player: idx,
+                    position,
+                });
+                self.decline_property(position);
+                self.continue_turn();
+            }
+            (TurnPhase::Auction { bidder, .. }, Action::Bid(bid)) => {
+                let money = self.players[bidder].money;
+                self.auction
+                    .as_mut()
+                    .expect("an auction is running")
+                    .place_bid(bidder, bid, money)
+                    .map_err(ActionError::Bid)?;
+                if !self.await_bid() {
+                    self.continue_turn();
+                }
+            }
+            (TurnPhase::DebtSettlement { amount, .. }, Action::PayDebt) => {
+                let money = self.players[idx].money;
+                if money < amount {
+                    return Err(ActionError::InsufficientFunds(InsufficientFunds {
+                        needed: amount,
+                        available: money,
+                    }));
+                }
+                self.continue_turn();
+            }
+            (TurnPhase::DebtSettlement { amount, .. }, Action::DeclareBankruptcy) => {
+                if self.players[idx].money >= amount {
+                    return Err(ActionError::NotAllowed {
+                        action,
+                        phase: self.phase,
+                    });
+                }
+                if !self.liquidation_actions(idx).is_empty() {
+                    return Err(ActionError::CanStillRaiseMoney);
+                }
+                let Some(Task::Payment { creditor, .. }) = self.tasks.pop_front() else {
+                    unreachable!("the debt being settled is the next task");
+                };
+                self.declare_bankruptcy(idx, creditor);
+                self.continue_turn();
+            }
+            (TurnPhase::PostRoll, Action::EndTurn) if !self.roll_again => self.end_turn(),
+            (phase, action) => return Err(ActionError::NotAllowed { action, phase }),
+        }
+
+        // Rejected actions leave the game as it was, so it only starts once one is applied
+        if self.state == GameState::Created {
+            self.state = GameState::Running;
+        }
+        self.started_at.get_or_insert_with(std::time::Instant::now);
+        self.check_for_end(false);
+        self.recording.finish_step(idx, &action);
+        self.push_undo_state(before, action);
+        Ok(())
+    }
+
+    /// The building, selling and mortgaging actions available to the player.
+    fn property_actions(&self, player: PlayerId) -> Vec<Action> {
+        let mut actions = vec![];
+        for position in 0..NUM_CELLS {
+            if self.check_buy_building(player, position).is_ok() {
+                actions.push(Action::BuyBuilding(position));
+            }
+            if self.check_sell_building(player, position).is_ok() {
+                actions.push(Action::SellBuilding(position));
+            }
+            if self.check_mortgage(player, position).is_ok() {
+                actions.push(Action::Mortgage(position));
+            }
+            if self.check_unmortgage(player, position).is_ok() {
+                actions.push(Action::Unmortgage(position));
+            }
+        }
+        actions
+    }
+
+    /// The ways the player can raise money to pay off a debt.
+    ///
+    /// Buildings come first, starting from the most expensive properties, then mortgages,
+    /// starting from the cheapest properties.
+    fn liquidation_actions(&self, player: PlayerId) -> Vec<Action> {
+        let sales = (0..NUM_CELLS)
+            .rev()
+            .filter(|&position| self.check_sell_building(player, position).is_ok())
+            .map(Action::SellBuilding);
+        let mortgages = (0..NUM_CELLS)
+            .filter(|&position| self.check_mortgage(player, position).is_ok())
+            .map(Action::Mortgage);
+        sales.chain(mortgages).collect()
+    }
+
+    /// Rolls the dice for the current player and resolves the roll.
+    ///
+    /// Rolling doubles lets the player roll again, unless it's their third in a row, which sends
+    /// them to jail. A jailed player leaves jail by rolling doubles (moving by that roll but not
+    /// rolling again), and must pay bail and move after their third failed attempt.
+    #[instrument(skip(self))]
+    fn roll(&mut self) {
+        let idx = self.current_player;
+        self.players[idx].roll_dice(self.dice.as_mut(), &mut self.rng);
+        self.phase = TurnPhase::Rolled;
+        self.rolled = true;
+        self.roll_again = false;
+
+        let doubles = self.players[idx].rolled_doubles();
+        self.emit(GameEvent::DiceRolled {
+            player: idx,
+            dice: self.players[idx].last_dice.clone().unwrap_or_default(),
+            doubles,
+        });
+        let player = &mut self.players[idx];
+        if player.in_jail {
+            if doubles {
+                tracing::info!("{} rolled doubles to leave jail", player.name);
+                self.tasks.push_back(Task::Release { player: idx });
+            } else {
+                player.jail_turns += 1;
+                if player.jail_turns < MAX_JAIL_TURNS {
+                    tracing::info!("{} failed to roll doubles", player.name);
+                    self.continue_turn();
+                    return;
+                }
+                self.pay_bail(idx);
+            }
+        } else if doubles {
+            self.doubles_rolled += 1;
+
+            // Go to jail if 3 doubles in a row
+            if self.doubles_rolled == 3 {
+                self.send_to_jail(idx);
+                self.continue_turn();
+                return;
+            }
+            self.roll_again = true;
+        }
+
+        self.tasks.push_back(Task::MoveByDice { player: idx });
+        self.continue_turn();
+    }
+
+    /// Works through the pending tasks, then moves on to the phase after the roll (or before it, if
+    /// the player hasn't rolled yet).
+    ///
+    /// Stops early if a task needs a decision, leaving the game in the phase that asks for it.
+    pub(crate) fn continue_turn(&mut self) {
+        if !self.run_tasks() {
+            return;
+        }
+
+        let player = &self.players[self.current_player];
+        if player.bankrupt {
+            self.end_turn();
+            return;
+        }
+        if player.in_jail {
+            self.roll_again = false;
+        }
+        self.phase = if self.rolled {
+            TurnPhase::PostRoll
+        } else {
+            TurnPhase::PreRoll
+        };
+    }
+
+    /// Runs pending tasks in order, skipping those for players that went bankrupt.
+    ///
+    /// Returns `false` if a task needs a decision before the rest can run.
+    fn run_tasks(&mut self) -> bool {
+        while let Some(task) = self.tasks.pop_front() {
+            match task {
+                Task::Payment {
+                    debtor,
+                    creditor,
+                    amount,
+                    rent,
+                } => {
+                    if self.players[debtor].bankrupt || self.pay(debtor, &creditor, amount, rent) {
+                        continue;
+                    }
+                    self.tasks.push_front(Task::Payment {
+                        debtor,
+                        creditor,
+                        amount,
+                        rent,
+                    });
+                    self.phase = TurnPhase::DebtSettlement { debtor, amount };
+                    return false;
+                }
+                Task::Offer { player, position } => {
+                    let owned = self
+                        .board
+                        .property_at(position)
+                        .is_none_or(|property| property.owner.is_some());
+                    if !self.players[player].bankrupt && !owned {
+                        self.phase = TurnPhase::AwaitingPurchaseDecision { position };
+                        return false;
+                    }
+                }
+                Task::Auction { position } => {
+                    if self.start_auction(position) {
+                        return false;
+                    }
+                }
+                Task::Release { player } => {
+                    if !self.players[player].bankrupt {
+                        self.release_from_jail(player);
+                    }
+                }
+                Task::MortgagedTransfer { player, position } => {
+                    let mortgaged = self
+                        .board
+                        .property_at(position)
+                        .is_some_and(|property| property.is_owned_by(player) && property.mortgaged);
+                    if !self.players[player].bankrupt && mortgaged {
+                        self.phase = TurnPhase::MortgagedTransfer { player, position };
+                        return false;
+                    }
+                }
+                Task::MoveByDice { player } => {
+                    if !self.players[player].bankrupt {
+                        self.move_by_dice(player);
+                        self.chained_moves = 0;
+                        self.resolve_position(player, RentRule::Normal);
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Starts auctioning the property at the given position among all players that haven't gone
+    /// bankrupt, unless it's been bought in the meantime.
+    ///
+    /// Returns `true` if the auction is waiting for a bid.
+    #[instrument(skip(self))]
+    fn start_auction(&mut self, position: CellIndex) -> bool {
+        let Some(property) = self.board.property_at(position) else {
+            return false;
+        };
+        if property.owner.is_some() {
+            return false;
+        }
+
+        let bidders = (0..self.players.len())
+            .filter(|&player| !self.players[player].bankrupt)
+            .collect();
+        self.auction = Some(Auction::new(
+            position,
+            bidders,
+            self.rules.auction_kind,
+            self.rules.min_bid_increment,
+        ));
+        self.await_bid()
+    }
+
+    /// Waits for the next bid in the running auction, or settles it if nobody is left to bid.
+    ///
+    /// Returns `true` if the auction is waiting for a bid.
+    fn await_bid(&mut self) -> bool {
+        let auction = self.auction.as_ref().expect("an auction is running");
+        match auction.next_bidder() {
+            Some(bidder) => {
+                self.phase = TurnPhase::Auction {
+                    position: auction.position(),
+                    bidder,
+                    minimum: auction.minimum_bid(),
+                };
+                true
+            }
+            None => {
+                self.finish_auction();
+                false
+            }
+        }
+    }
+
+    /// Settles the running auction, handing the property to the winner for their bid.
+    fn finish_auction(&mut self) {
+        let auction = self.auction.take().expect("an auction is running");
+        let position = auction.position();
+        let property = self
+            .board
+            .property_at(position)
+            .expect("auctioned cells are properties");
+        match auction.result() {
+            AuctionResult::Won { winner, price } => {
+                tracing::info!(
+                    "{} won the auction for {} at ${}",
+                    self.players[winner].name,
+                    property.name,
+                    price
+                );
+                self.buy_property(winner, position, price);
+                self.emit(GameEvent::AuctionWon {
+                    player: winner,
+                    position,
+                    price,
+                });
+            }
+            AuctionResult::NoBids => {
+                tracing::info!("Nobody bid on {}", property.name);
+                self.emit(GameEvent::AuctionUnsold { position });
+            }
+        }
+    }
+
+    /// Ends the current player's turn and passes it on.
+    fn end_turn(&mut self) {
+        self.phase = TurnPhase::EndTurn;
+        self.emit(GameEvent::TurnEnded {
+            player: self.current_player,
+        });
+        self.rolled = false;
+        self.roll_again = false;
+        self.doubles_rolled = 0;
+        self.next_player();
+        self.phase = TurnPhase::PreRoll;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{give, players, MEDITERRANEAN_AVENUE};
+
+    /// A game where the first player owes the bank `amount` with no cash, but can mortgage
+    /// Mediterranean Avenue for $30.
+    fn in_debt(amount: usize) -> Game {
+        let mut game = Game::new(players());
+        give(&mut game, 0, MEDITERRANEAN_AVENUE);
+        game.players[0].money = 0;
+        game.pay_bank(0, amount);
+        game.continue_turn();
+        game
+    }
+
+    #[test]
+    fn rejected_action_does_not_start_the_game() {
+        let mut game = Game::new(players());
+        assert!(game.apply(Action::EndTurn).is_err());
+        assert_eq!(game.state(), GameState::Created);
+
+        // Seeding is only allowed before the game starts
+        let mut game = game.with_seed(3);
+        game.apply(Action::RollDice).unwrap();
+        assert_eq!(game.state(), GameState::Running);
+    }
+
+    #[test]
+    fn solvent_debtor_cannot_declare_bankruptcy() {
+        let mut game = in_debt(20);
+        assert_eq!(
+            game.phase(),
+            TurnPhase::DebtSettlement {
+                debtor: 0,
+                amount: 20
+            }
+        );
+        assert_eq!(
+            game.apply(Action::DeclareBankruptcy),
+            Err(ActionError::CanStillRaiseMoney)
+        );
+
+        game.apply(Action::Mortgage(MEDITERRANEAN_AVENUE)).unwrap();
+        assert_eq!(game.legal_actions(), [Action::PayDebt]);
+        assert!(matches!(
+            game.apply(Action::DeclareBankruptcy),
+            Err(ActionError::NotAllowed { .. })
+        ));
+        assert!(!game.players[0].bankrupt);
+
+        game.apply(Action::PayDebt).unwrap();
+        assert_eq!(game.players[0].money, 10);
+        assert_eq!(game.phase(), TurnPhase::PreRoll);
+    }
+}
@@ -1,3 +1,5 @@
-use std::{cell::RefCell, rc::Rc};
+/// Identifies a player by their index in the turn order.
+pub type PlayerId = usize;
 
-pub(crate) type RcCell<T> = Rc<RefCell<T>>;
+/// Identifies a cell by its position on the board.
+pub type CellIndex = usize;
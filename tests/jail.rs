@@ -0,0 +1,53 @@
+use monopoly::{Game, GameEvent, Player, ScriptedDice};
+
+fn game(dice: ScriptedDice) -> Game {
+    Game::new(vec![Player::new("P1"), Player::new("P2")])
+        .with_dice(dice)
+        .with_event_queue()
+}
+
+fn rolls(events: &[GameEvent], player: usize) -> usize {
+    events
+        .iter()
+        .filter(|event| matches!(event, GameEvent::DiceRolled { player: p, .. } if *p == player))
+        .count()
+}
+
+#[test]
+fn third_doubles_in_a_row_send_the_player_to_jail() {
+    let mut game = game(ScriptedDice::new([(3, 3), (3, 3), (3, 3)]));
+    game.step();
+
+    let events: Vec<_> = game.drain_events().collect();
+    assert_eq!(rolls(&events, 0), 3);
+    assert_eq!(
+        events.last(),
+        Some(&GameEvent::TurnEnded { player: 0 }),
+        "the turn ends right after going to jail"
+    );
+    assert!(events.contains(&GameEvent::SentToJail { player: 0 }));
+    assert!(game.view(0).in_jail(0));
+    assert_eq!(game.view(0).position(0), 10);
+    assert_eq!(game.current_player(), 1);
+}
+
+#[test]
+fn doubles_in_jail_move_the_player_without_rolling_again() {
+    let mut game = game(ScriptedDice::new([(3, 3), (3, 3), (3, 3), (1, 2), (2, 2)]));
+    game.step();
+    game.step();
+    game.drain_events().for_each(drop);
+
+    game.step();
+    let events: Vec<_> = game.drain_events().collect();
+    assert_eq!(rolls(&events, 0), 1);
+    assert!(events.contains(&GameEvent::LeftJail { player: 0 }));
+    assert!(events.contains(&GameEvent::Moved {
+        player: 0,
+        from: 10,
+        to: 14
+    }));
+    assert!(!game.view(0).in_jail(0));
+    assert_eq!(game.view(0).position(0), 14);
+    assert_eq!(game.current_player(), 1);
+}
@@ -0,0 +1,45 @@
+use monopoly::{Action, Game, GameView, Player, PropertyInfo, Rules, Strategy};
+
+/// Keeps the mortgage on every property it's handed.
+#[derive(Debug)]
+struct NeverUnmortgage;
+
+impl Strategy for NeverUnmortgage {
+    fn wants_to_unmortgage(&self, _view: &GameView, _property: &PropertyInfo) -> bool {
+        false
+    }
+}
+
+#[test]
+fn replay_records_mortgaged_transfer_decisions() {
+    let rules = Rules {
+        max_rounds: Some(300),
+        ..Default::default()
+    };
+    let players = vec![
+        Player::with_strategy("P1", NeverUnmortgage),
+        Player::with_strategy("P2", NeverUnmortgage),
+        Player::with_strategy("P3", NeverUnmortgage),
+    ];
+    let mut game = Game::with_rules(players, rules).with_seed(67).with_replay();
+    game.run();
+
+    let replay = game.replay().expect("the game is recorded");
+    assert!(replay
+        .steps
+        .iter()
+        .any(|step| step.action == Action::KeepMortgaged));
+    let replayed = replay.verify().expect("the replay matches the game");
+    assert_eq!(replayed.outcome(), game.outcome());
+}
+
+#[test]
+fn games_are_only_recorded_on_request() {
+    let rules = Rules {
+        max_rounds: Some(20),
+        ..Default::default()
+    };
+    let mut game = Game::with_rules(vec![Player::new("P1"), Player::new("P2")], rules);
+    game.run();
+    assert!(game.replay().is_none());
+}
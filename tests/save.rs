@@ -0,0 +1,53 @@
+use monopoly::{
+    AggressiveBuilder, ColorGroupCollector, Game, Player, RailroadHoarder, Rules, SaveError,
+};
+
+fn game() -> Game {
+    let rules = Rules {
+        max_rounds: Some(200),
+        ..Default::default()
+    };
+    let players = vec![
+        Player::with_strategy("P1", AggressiveBuilder::default()),
+        Player::with_strategy("P2", ColorGroupCollector::default()),
+        Player::with_strategy("P3", RailroadHoarder::default()),
+    ];
+    Game::with_rules(players, rules)
+        .with_seed(7)
+        .with_event_queue()
+}
+
+#[test]
+fn loaded_game_plays_on_like_the_saved_one() {
+    let mut game = game();
+    for _ in 0..60 {
+        game.step();
+    }
+    assert!(game.outcome().is_none(), "the game is saved mid-game");
+
+    let mut save = vec![];
+    game.save(&mut save).unwrap();
+    game.drain_events().for_each(drop);
+    let mut loaded = Game::load(save.as_slice()).unwrap().with_event_queue();
+    loaded.set_strategy(0, AggressiveBuilder::default());
+    loaded.set_strategy(1, ColorGroupCollector::default());
+    loaded.set_strategy(2, RailroadHoarder::default());
+
+    let outcome = game.run();
+    assert_eq!(loaded.run(), outcome);
+    assert!(loaded.drain_events().eq(game.drain_events()));
+}
+
+#[test]
+fn newer_save_formats_are_rejected() {
+    let mut save = vec![];
+    game().save(&mut save).unwrap();
+    let save = String::from_utf8(save)
+        .unwrap()
+        .replacen("\"version\": 1", "\"version\": 999", 1);
+
+    assert!(matches!(
+        Game::load(save.as_bytes()),
+        Err(SaveError::UnsupportedVersion { version: 999 })
+    ));
+}